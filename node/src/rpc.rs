@@ -7,13 +7,24 @@
 
 use std::sync::Arc;
 
-use jsonrpsee::RpcModule;
-use rmrk_substrate_runtime::{opaque::Block, AccountId, Balance, Index};
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+	RpcModule,
+};
+use rmrk_rpc_runtime_api::RmrkApi as RmrkRuntimeApi;
+use rmrk_substrate_runtime::{opaque::Block, AccountId, Balance, Index, RmrkParts, RmrkString};
+use rmrk_traits::{
+	primitives::{CollectionId, NftId},
+	PendingItem,
+};
 pub use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
 
 /// Full client dependencies.
 pub struct FullDeps<C, P> {
@@ -25,6 +36,96 @@ pub struct FullDeps<C, P> {
 	pub deny_unsafe: DenyUnsafe,
 }
 
+/// RMRK RPC methods, answered from the state of the given block or of the best block.
+#[rpc(server)]
+pub trait RmrkApi<BlockHash> {
+	/// Get a page of the NFTs root-owned by an account, skipping the first `start` NFTs and
+	/// returning at most `count`
+	#[method(name = "rmrk_nftsOwnedBy")]
+	fn nfts_owned_by(
+		&self,
+		account: AccountId,
+		start: u32,
+		count: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(CollectionId, NftId)>>;
+
+	/// Get a page of the pending NFTs and resource changes awaiting the decision of an account,
+	/// skipping the first `start` items and returning at most `count`
+	#[method(name = "rmrk_pendingInbox")]
+	fn pending_inbox(
+		&self,
+		account: AccountId,
+		start: u32,
+		count: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<PendingItem>>;
+}
+
+/// Error code of runtime API calls that failed.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Implements the RMRK RPC methods through the RMRK runtime API.
+pub struct Rmrk<C> {
+	client: Arc<C>,
+}
+
+impl<C> Rmrk<C> {
+	/// Create new `Rmrk` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+
+	fn block_id(&self, at: Option<<Block as BlockT>::Hash>) -> BlockId<Block>
+	where
+		C: HeaderBackend<Block>,
+	{
+		BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+	}
+}
+
+/// Turns the failure of a runtime API call into an RPC error.
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Unable to query RMRK state.",
+		Some(format!("{:?}", err)),
+	))
+	.into()
+}
+
+impl<C> RmrkApiServer<<Block as BlockT>::Hash> for Rmrk<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: RmrkRuntimeApi<Block, AccountId, RmrkString, RmrkParts>,
+{
+	fn nfts_owned_by(
+		&self,
+		account: AccountId,
+		start: u32,
+		count: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(CollectionId, NftId)>> {
+		self.client
+			.runtime_api()
+			.nfts_owned_by(&self.block_id(at), account, start, count)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn pending_inbox(
+		&self,
+		account: AccountId,
+		start: u32,
+		count: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<PendingItem>> {
+		self.client
+			.runtime_api()
+			.pending_inbox(&self.block_id(at), account, start, count)
+			.map_err(runtime_error_into_rpc_err)
+	}
+}
+
 /// Instantiate all full RPC extensions.
 pub fn create_full<C, P>(
 	deps: FullDeps<C, P>,
@@ -36,6 +137,7 @@ where
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BlockBuilder<Block>,
+	C::Api: RmrkRuntimeApi<Block, AccountId, RmrkString, RmrkParts>,
 	P: TransactionPool + 'static,
 {
 	use pallet_transaction_payment_rpc::{TransactionPaymentApiServer, TransactionPayment};
@@ -45,7 +147,8 @@ where
 	let FullDeps { client, pool, deny_unsafe } = deps;
 
 	module.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Rmrk::new(client).into_rpc())?;
 
 	// Extend this RPC with a custom API by using the following syntax.
 	// `YourRpcStruct` should have a reference to a client, which is needed
@@ -5,12 +5,16 @@
 #![allow(clippy::too_many_arguments)]
 
 use super::*;
-use codec::{Codec, Decode, Encode};
-use frame_support::traits::tokens::Locker;
+use codec::{Codec, Decode, DecodeAll, Encode};
+use frame_support::{
+	traits::{tokens::Locker, BalanceStatus},
+	weights::Weight,
+};
 use sp_runtime::{
-	traits::{Saturating, TrailingZeroInput},
-	ArithmeticError,
+	traits::{CheckedSub, One, Saturating, TrailingZeroInput, Zero},
+	ArithmeticError, PerThing,
 };
+use sp_std::vec::Vec;
 
 // Randomness to generate NFT virtual accounts
 pub const SALT_RMRK_NFT: &[u8; 8] = b"RmrkNft/";
@@ -54,19 +58,147 @@ where
 	) -> DispatchResult {
 		let collection =
 			Collections::<T>::get(&collection_id).ok_or(Error::<T>::CollectionUnknown)?;
-		ensure!(collection.issuer == sender, Error::<T>::NoPermission);
-		if let Some(nft_id) = &maybe_nft_id {
-			// Check NFT lock status
+		let old_value = Properties::<T>::get((&collection_id, maybe_nft_id, &key));
+		Self::ensure_property_writable(
+			&sender,
+			&collection.issuer,
+			collection_id,
+			maybe_nft_id,
+			&key,
+			old_value.is_some(),
+		)?;
+		Self::ensure_property_matches_schema(collection_id, &key, &value)?;
+		if let (None, Some(nft_id)) = (&old_value, maybe_nft_id) {
 			ensure!(
-				!Pallet::<T>::is_locked(collection_id, *nft_id),
-				pallet_uniques::Error::<T>::Locked
+				Properties::<T>::iter_key_prefix((collection_id, Some(nft_id))).count() <
+					T::MaxPropertiesPerNft::get() as usize,
+				Error::<T>::TooManyProperties
+			);
+		}
+
+		// Release the deposit of the previous value to whoever paid it, then reserve the new
+		// deposit from the sender: the issuer holds it for the collection, any other writer for
+		// itself
+		if let Some(old_value) = old_value {
+			Self::release_property_deposit(
+				&collection.issuer,
+				(collection_id, maybe_nft_id, &key),
+				&old_value,
 			);
-			let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, *nft_id)?;
-			ensure!(root_owner == collection.issuer, Error::<T>::NoPermission);
 		}
+		let deposit = Self::property_deposit(&key, &value);
+		if sender == collection.issuer {
+			Self::reserve_collection_deposit(collection_id, &collection.issuer, deposit)?;
+		} else {
+			<T as Config>::Currency::reserve(&sender, deposit)?;
+			PropertyDeposits::<T>::insert((&collection_id, maybe_nft_id, &key), (sender, deposit));
+		}
+
 		Properties::<T>::insert((&collection_id, maybe_nft_id, &key), &value);
 		Ok(())
 	}
+
+	fn property_remove(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		maybe_nft_id: Option<NftId>,
+		key: KeyLimitOf<T>,
+	) -> DispatchResult {
+		let collection =
+			Collections::<T>::get(&collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		let value = Properties::<T>::get((&collection_id, maybe_nft_id, &key))
+			.ok_or(Error::<T>::PropertyDoesntExist)?;
+		Self::ensure_property_writable(
+			&sender,
+			&collection.issuer,
+			collection_id,
+			maybe_nft_id,
+			&key,
+			true,
+		)?;
+
+		Properties::<T>::remove((&collection_id, maybe_nft_id, &key));
+		Self::release_property_deposit(
+			&collection.issuer,
+			(collection_id, maybe_nft_id, &key),
+			&value,
+		);
+		Ok(())
+	}
+
+	fn property_set_permission(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		key: KeyLimitOf<T>,
+		permission: PropertyPermission,
+	) -> DispatchResult {
+		let collection =
+			Collections::<T>::get(&collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+		// An immutable key stays immutable
+		ensure!(
+			PropertyPermissions::<T>::get(collection_id, &key) != PropertyPermission::Immutable,
+			Error::<T>::PropertyImmutable
+		);
+		PropertyPermissions::<T>::insert(collection_id, &key, permission);
+		Ok(())
+	}
+
+	fn property_set_schema(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		key: KeyLimitOf<T>,
+		property_type: Option<PropertyType>,
+	) -> DispatchResult {
+		let collection =
+			Collections::<T>::get(&collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+		PropertySchemas::<T>::set(collection_id, &key, property_type);
+		Ok(())
+	}
+
+	fn owner_property_set(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		key: KeyLimitOf<T>,
+		value: ValueLimitOf<T>,
+	) -> DispatchResult {
+		Self::ensure_owner_property_writable(&sender, collection_id, nft_id)?;
+		Self::ensure_property_matches_schema(collection_id, &key, &value)?;
+		if !OwnerProperties::<T>::contains_key((&collection_id, nft_id, &key)) {
+			ensure!(
+				OwnerProperties::<T>::iter_key_prefix((collection_id, nft_id)).count() <
+					T::MaxPropertiesPerNft::get() as usize,
+				Error::<T>::TooManyProperties
+			);
+		}
+
+		// Release the deposit of the previous value, possibly reserved by a previous owner
+		Self::release_owner_property_deposit((collection_id, nft_id, &key));
+		let deposit = Self::property_deposit(&key, &value);
+		<T as Config>::Currency::reserve(&sender, deposit)?;
+		OwnerPropertyDeposits::<T>::insert((&collection_id, nft_id, &key), (sender, deposit));
+		OwnerProperties::<T>::insert((&collection_id, nft_id, &key), &value);
+		Ok(())
+	}
+
+	fn owner_property_remove(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		key: KeyLimitOf<T>,
+	) -> DispatchResult {
+		ensure!(
+			OwnerProperties::<T>::contains_key((&collection_id, nft_id, &key)),
+			Error::<T>::PropertyDoesntExist
+		);
+		Self::ensure_owner_property_writable(&sender, collection_id, nft_id)?;
+
+		OwnerProperties::<T>::remove((&collection_id, nft_id, &key));
+		Self::release_owner_property_deposit((collection_id, nft_id, &key));
+		Ok(())
+	}
 }
 
 impl<T: Config>
@@ -90,24 +222,15 @@ where
 		// Check NFT lock status
 		ensure!(!Pallet::<T>::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
 
-		match resource.clone() {
-			ResourceTypes::Basic(_r) => (),
-			ResourceTypes::Composable(r) => {
-				EquippableBases::<T>::insert((collection_id, nft_id, r.base), ());
-				if let Some((base, slot)) = r.slot {
-					EquippableSlots::<T>::insert(
-						(collection_id, nft_id, resource_id, base, slot),
-						(),
-					);
-				}
-			},
-			ResourceTypes::Slot(r) => {
-				EquippableSlots::<T>::insert(
-					(collection_id, nft_id, resource_id, r.base, r.slot),
-					(),
-				);
-			},
-		}
+		Self::ensure_valid_resource(&resource)?;
+		ensure!(
+			Resources::<T>::iter_key_prefix((collection_id, nft_id)).count() <
+				T::MaxResourcesPerNft::get() as usize,
+			Error::<T>::TooManyResources
+		);
+		Self::reserve_collection_deposit(collection_id, &sender, T::ResourceDeposit::get())?;
+
+		Self::add_resource_indexes(collection_id, nft_id, resource_id, &resource);
 
 		// Resource should be in a pending state if the rootowner of the resource is not the sender
 		// of the transaction, unless the resource is being added on mint.  This prevents the
@@ -126,6 +249,13 @@ where
 				resource,
 			};
 		Resources::<T>::insert((collection_id, nft_id, resource_id), res);
+		if pending {
+			PendingInbox::<T>::insert(
+				root_owner,
+				PendingItem::ResourceAddition { collection_id, nft_id, resource_id },
+				(),
+			);
+		}
 
 		Ok(resource_id)
 	}
@@ -149,6 +279,10 @@ where
 				Ok(())
 			},
 		)?;
+		PendingInbox::<T>::remove(
+			&root_owner,
+			PendingItem::ResourceAddition { collection_id, nft_id, resource_id },
+		);
 
 		Self::deposit_event(Event::ResourceAccepted { nft_id, resource_id });
 		Ok(())
@@ -169,7 +303,12 @@ where
 		);
 
 		if root_owner == sender {
-			Resources::<T>::remove((collection_id, nft_id, resource_id));
+			Self::delete_resource(collection_id, nft_id, resource_id);
+			Self::release_collection_deposit(
+				collection_id,
+				&collection.issuer,
+				T::ResourceDeposit::get(),
+			);
 		} else {
 			Resources::<T>::try_mutate_exists(
 				(collection_id, nft_id, resource_id),
@@ -180,6 +319,11 @@ where
 					Ok(())
 				},
 			)?;
+			PendingInbox::<T>::insert(
+				root_owner,
+				PendingItem::ResourceRemoval { collection_id, nft_id, resource_id },
+				(),
+			);
 		}
 
 		Ok(())
@@ -193,28 +337,185 @@ where
 	) -> DispatchResult {
 		let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id)?;
 		ensure!(root_owner == sender, Error::<T>::NoPermission);
+		let resource = Resources::<T>::get((collection_id, nft_id, resource_id))
+			.ok_or(Error::<T>::ResourceDoesntExist)?;
+		ensure!(resource.pending_removal, Error::<T>::ResourceNotPending);
+		Self::delete_resource(collection_id, nft_id, resource_id);
+
+		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		Self::release_collection_deposit(
+			collection_id,
+			&collection.issuer,
+			T::ResourceDeposit::get(),
+		);
+
+		Ok(())
+	}
+
+	fn reject(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		resource_id: ResourceId,
+	) -> DispatchResult {
+		let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id)?;
+		ensure!(root_owner == sender, Error::<T>::NoPermission);
+		// Check NFT lock status
+		ensure!(!Pallet::<T>::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+		let resource = Resources::<T>::get((collection_id, nft_id, resource_id))
+			.ok_or(Error::<T>::ResourceDoesntExist)?;
+
+		// Rejecting a pending replacement keeps the current resource
+		if PendingResourceReplacements::<T>::take((collection_id, nft_id, resource_id)).is_some() {
+			PendingInbox::<T>::remove(
+				&root_owner,
+				PendingItem::ResourceReplacement { collection_id, nft_id, resource_id },
+			);
+			return Ok(())
+		}
+		ensure!(resource.pending, Error::<T>::ResourceNotPending);
+
+		Self::delete_resource(collection_id, nft_id, resource_id);
+
+		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		Self::release_collection_deposit(
+			collection_id,
+			&collection.issuer,
+			T::ResourceDeposit::get(),
+		);
+
+		Ok(())
+	}
+
+	fn resource_replace(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		resource_id: ResourceId,
+		resource: ResourceTypeOf<T>,
+	) -> DispatchResult {
+		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+		let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id)?;
+		// Check NFT lock status
+		ensure!(!Pallet::<T>::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+		let current = Resources::<T>::get((collection_id, nft_id, resource_id))
+			.ok_or(Error::<T>::ResourceDoesntExist)?;
+		Self::ensure_valid_resource(&resource)?;
+
+		// A resource still awaiting acceptance is replaced in place and stays pending
+		let item = PendingItem::ResourceReplacement { collection_id, nft_id, resource_id };
+		if root_owner == sender || current.pending {
+			PendingResourceReplacements::<T>::remove((collection_id, nft_id, resource_id));
+			PendingInbox::<T>::remove(&root_owner, item);
+			Self::swap_resource(collection_id, nft_id, resource_id, resource);
+		} else {
+			PendingResourceReplacements::<T>::insert(
+				(collection_id, nft_id, resource_id),
+				resource,
+			);
+			PendingInbox::<T>::insert(root_owner, item, ());
+		}
+		Ok(())
+	}
+
+	fn accept_replacement(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		resource_id: ResourceId,
+	) -> DispatchResult {
+		let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id)?;
+		ensure!(root_owner == sender, Error::<T>::NoPermission);
+		// Check NFT lock status
+		ensure!(!Pallet::<T>::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+		let resource = PendingResourceReplacements::<T>::take((collection_id, nft_id, resource_id))
+			.ok_or(Error::<T>::ResourceNotPending)?;
+		PendingInbox::<T>::remove(
+			&root_owner,
+			PendingItem::ResourceReplacement { collection_id, nft_id, resource_id },
+		);
+
+		Self::swap_resource(collection_id, nft_id, resource_id, resource);
+		Ok(())
+	}
+
+	fn collection_resource_add(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		resource: ResourceTypes<BoundedVec<u8, T::StringLimit>, BoundedVec<PartId, T::PartsLimit>>,
+	) -> Result<ResourceId, DispatchError> {
+		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+
+		Self::ensure_valid_resource(&resource)?;
+		// Shared resources count towards the resources of every NFT of the collection
+		ensure!(
+			CollectionResources::<T>::iter_key_prefix(collection_id).count() <
+				T::MaxResourcesPerNft::get() as usize,
+			Error::<T>::TooManyResources
+		);
+		let resource_id = NextCollectionResourceId::<T>::try_mutate(collection_id, |id| {
+			let current_id = *id;
+			*id = id.checked_add(1).ok_or(Error::<T>::NoAvailableResourceId)?;
+			Ok::<ResourceId, Error<T>>(current_id)
+		})?;
+		// A shared resource costs a single deposit, however many NFTs display it
+		Self::reserve_collection_deposit(collection_id, &sender, T::ResourceDeposit::get())?;
+
+		CollectionResources::<T>::insert(collection_id, resource_id, resource);
+		Ok(resource_id)
+	}
+
+	fn collection_resource_remove(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		resource_id: ResourceId,
+	) -> DispatchResult {
+		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		ensure!(collection.issuer == sender, Error::<T>::NoPermission);
 		ensure!(
-			Resources::<T>::contains_key((collection_id, nft_id, &resource_id)),
+			CollectionResources::<T>::take(collection_id, resource_id).is_some(),
 			Error::<T>::ResourceDoesntExist
 		);
 
-		Resources::<T>::try_mutate_exists(
-			(collection_id, nft_id, resource_id),
-			|resource| -> DispatchResult {
-				if let Some(res) = resource {
-					ensure!(res.pending_removal, Error::<T>::ResourceNotPending);
-					*resource = None;
-				}
-				Ok(())
-			},
-		)?;
+		CollectionResourceOptOuts::<T>::remove_prefix((collection_id, resource_id), None);
+		Self::release_collection_deposit(collection_id, &sender, T::ResourceDeposit::get());
+		Ok(())
+	}
+
+	fn collection_resource_set_opt_out(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		resource_id: ResourceId,
+		opt_out: bool,
+	) -> DispatchResult {
+		let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id)?;
+		ensure!(root_owner == sender, Error::<T>::NoPermission);
+		// Check NFT lock status
+		ensure!(!Pallet::<T>::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+		ensure!(
+			CollectionResources::<T>::contains_key(collection_id, resource_id),
+			Error::<T>::ResourceDoesntExist
+		);
 
+		if opt_out {
+			CollectionResourceOptOuts::<T>::insert((collection_id, resource_id, nft_id), ());
+		} else {
+			CollectionResourceOptOuts::<T>::remove((collection_id, resource_id, nft_id));
+		}
 		Ok(())
 	}
 }
 
-impl<T: Config> Collection<StringLimitOf<T>, BoundedCollectionSymbolOf<T>, T::AccountId>
-	for Pallet<T>
+impl<T: Config>
+	Collection<
+		StringLimitOf<T>,
+		BoundedCollectionSymbolOf<T>,
+		T::AccountId,
+		BoundedRoyaltySharesOf<T>,
+	> for Pallet<T>
 where
 	T: pallet_uniques::Config<CollectionId = CollectionId, ItemId = NftId>,
 {
@@ -226,8 +527,10 @@ where
 		metadata: StringLimitOf<T>,
 		max: Option<u32>,
 		symbol: BoundedCollectionSymbolOf<T>,
+		royalty: Option<RoyaltyInfoOf<T>>,
 	) -> Result<CollectionId, DispatchError> {
-		let collection = CollectionInfo { issuer, metadata, max, symbol, nfts_count: 0 };
+		Self::ensure_valid_royalty(&royalty)?;
+		let collection = CollectionInfo { issuer, metadata, max, symbol, nfts_count: 0, royalty };
 		let collection_id =
 			<CollectionIndex<T>>::try_mutate(|n| -> Result<CollectionId, DispatchError> {
 				let id = *n;
@@ -242,6 +545,18 @@ where
 	fn collection_burn(_issuer: T::AccountId, collection_id: CollectionId) -> DispatchResult {
 		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
 		ensure!(collection.nfts_count == 0, Error::<T>::CollectionNotEmpty);
+		// Remove collection-level properties and release the remaining deposit
+		Properties::<T>::remove_prefix((collection_id,), None);
+		PropertyPermissions::<T>::remove_prefix(collection_id, None);
+		PropertySchemas::<T>::remove_prefix(collection_id, None);
+		// Remove shared resources, whose deposits are part of the collection deposit
+		CollectionResources::<T>::remove_prefix(collection_id, None);
+		CollectionResourceOptOuts::<T>::remove_prefix((collection_id,), None);
+		NextCollectionResourceId::<T>::remove(collection_id);
+		let deposit = CollectionDeposits::<T>::take(collection_id);
+		<T as Config>::Currency::unreserve(&collection.issuer, deposit);
+		CollectionMetadataFrozen::<T>::remove(collection_id);
+		NextNftId::<T>::remove(collection_id);
 		Collections::<T>::remove(collection_id);
 		Ok(())
 	}
@@ -254,6 +569,13 @@ where
 
 		Collections::<T>::try_mutate_exists(collection_id, |collection| -> DispatchResult {
 			if let Some(col) = collection {
+				// Move the reserved deposit over to the new issuer
+				<T as Config>::Currency::repatriate_reserved(
+					&col.issuer,
+					&new_issuer,
+					Self::collection_deposits(collection_id),
+					BalanceStatus::Reserved,
+				)?;
 				col.issuer = new_issuer.clone();
 			}
 			Ok(())
@@ -274,9 +596,43 @@ where
 		})?;
 		Ok(collection_id)
 	}
+
+	fn collection_set_royalty(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		royalty: Option<RoyaltyInfoOf<T>>,
+	) -> DispatchResult {
+		Self::ensure_valid_royalty(&royalty)?;
+		Collections::<T>::try_mutate_exists(collection_id, |collection| -> DispatchResult {
+			let collection = collection.as_mut().ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+			collection.royalty = royalty;
+			Ok(())
+		})
+	}
+
+	fn collection_set_metadata(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		metadata: StringLimitOf<T>,
+		freeze: bool,
+	) -> DispatchResult {
+		ensure!(!CollectionMetadataFrozen::<T>::get(collection_id), Error::<T>::MetadataFrozen);
+		Collections::<T>::try_mutate_exists(collection_id, |collection| -> DispatchResult {
+			let collection = collection.as_mut().ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+			collection.metadata = metadata;
+			Ok(())
+		})?;
+		// Freezing is one-way, frozen metadata can never be changed again
+		if freeze {
+			CollectionMetadataFrozen::<T>::insert(collection_id, true);
+		}
+		Ok(())
+	}
 }
 
-impl<T: Config> Nft<T::AccountId, StringLimitOf<T>> for Pallet<T>
+impl<T: Config> Nft<T::AccountId, StringLimitOf<T>, BoundedRoyaltySharesOf<T>> for Pallet<T>
 where
 	T: pallet_uniques::Config<CollectionId = CollectionId, ItemId = NftId>,
 {
@@ -286,8 +642,7 @@ where
 		sender: T::AccountId,
 		owner: T::AccountId,
 		collection_id: CollectionId,
-		royalty_recipient: Option<T::AccountId>,
-		royalty_amount: Option<Permill>,
+		royalty: Option<RoyaltyInfoOf<T>>,
 		metadata: StringLimitOf<T>,
 		transferable: bool,
 	) -> sp_std::result::Result<(CollectionId, NftId), DispatchError> {
@@ -302,22 +657,11 @@ where
 		// NFT should be pending if minting to another account
 		let pending = owner != sender;
 
-		let mut royalty: Option<RoyaltyInfo<T::AccountId>> = None;
-
-		if let Some(amount) = royalty_amount {
-			match royalty_recipient {
-				Some(recipient) => {
-					royalty = Some(RoyaltyInfo::<T::AccountId> { recipient, amount });
-				},
-				None => {
-					// If a royalty amount is passed but no recipient, defaults to the sender
-					royalty = Some(RoyaltyInfo::<T::AccountId> { recipient: sender, amount });
-				},
-			}
-		};
+		let royalty = Self::royalty_or_minter_share(&sender, royalty);
+		Self::ensure_valid_royalty(&royalty)?;
 
 		let nft = NftInfo {
-			owner: AccountIdOrCollectionNftTuple::AccountId(owner),
+			owner: AccountIdOrCollectionNftTuple::AccountId(owner.clone()),
 			royalty,
 			metadata,
 			equipped: false,
@@ -326,6 +670,18 @@ where
 		};
 
 		Nfts::<T>::insert(collection_id, nft_id, nft);
+		NftsByRootOwner::<T>::insert(&owner, (collection_id, nft_id), ());
+
+		// A rejected pending NFT is returned to the issuer that minted it
+		if pending {
+			Self::set_pending_nft(
+				collection_id,
+				nft_id,
+				sender.clone(),
+				AccountIdOrCollectionNftTuple::AccountId(sender),
+			);
+			PendingInbox::<T>::insert(owner, PendingItem::Nft { collection_id, nft_id }, ());
+		}
 
 		// increment nfts counter
 		let nfts_count = collection.nfts_count.checked_add(1).ok_or(ArithmeticError::Overflow)?;
@@ -342,8 +698,7 @@ where
 		sender: T::AccountId,
 		owner: (CollectionId, NftId),
 		collection_id: CollectionId,
-		royalty_recipient: Option<T::AccountId>,
-		royalty_amount: Option<Permill>,
+		royalty: Option<RoyaltyInfoOf<T>>,
 		metadata: StringLimitOf<T>,
 		transferable: bool,
 	) -> sp_std::result::Result<(CollectionId, NftId), DispatchError> {
@@ -358,21 +713,13 @@ where
 		// Calculate the rootowner of the intended owner of the minted NFT
 		let (rootowner, _) = Self::lookup_root_owner(owner.0, owner.1)?;
 
-		// NFT should be pending if minting either to an NFT owned by another account
-		let pending = rootowner != sender;
-
-		let mut royalty: Option<RoyaltyInfo<T::AccountId>> = None;
+		// NFT should be pending if minting either to an NFT owned by another account, unless
+		// its policy auto-accepts it
+		let pending =
+			rootowner != sender && Self::check_incoming_policy(&rootowner, &sender, collection_id)?;
 
-		if let Some(amount) = royalty_amount {
-			match royalty_recipient {
-				Some(recipient) => {
-					royalty = Some(RoyaltyInfo::<T::AccountId> { recipient, amount });
-				},
-				None => {
-					royalty = Some(RoyaltyInfo::<T::AccountId> { recipient: rootowner, amount });
-				},
-			}
-		};
+		let royalty = Self::royalty_or_minter_share(&sender, royalty);
+		Self::ensure_valid_royalty(&royalty)?;
 
 		let nft = NftInfo {
 			owner: AccountIdOrCollectionNftTuple::CollectionAndNftTuple(owner.0, owner.1),
@@ -384,6 +731,20 @@ where
 		};
 
 		Nfts::<T>::insert(collection_id, nft_id, nft);
+		NftsByRootOwner::<T>::insert(&rootowner, (collection_id, nft_id), ());
+
+		Self::add_child(owner, (collection_id, nft_id), sender.clone())?;
+
+		// A rejected pending NFT is returned to the issuer that minted it
+		if pending {
+			Self::set_pending_nft(
+				collection_id,
+				nft_id,
+				sender.clone(),
+				AccountIdOrCollectionNftTuple::AccountId(sender),
+			);
+			PendingInbox::<T>::insert(rootowner, PendingItem::Nft { collection_id, nft_id }, ());
+		}
 
 		// increment nfts counter
 		let nfts_count = collection.nfts_count.checked_add(1).ok_or(ArithmeticError::Overflow)?;
@@ -401,34 +762,24 @@ where
 		nft_id: NftId,
 		max_recursions: u32,
 	) -> sp_std::result::Result<(CollectionId, NftId), DispatchError> {
-		ensure!(max_recursions > 0, Error::<T>::TooManyRecursions);
+		// A tree too deep is not burned at all
+		let nfts = Self::subtree((collection_id, nft_id))?;
+		ensure!(
+			nfts.iter().all(|(_, depth)| *depth < max_recursions),
+			Error::<T>::TooManyRecursions
+		);
 
-		// Remove self from parent's Children storage
-		if let Some(nft) = Self::nfts(collection_id, nft_id) {
-			if let AccountIdOrCollectionNftTuple::CollectionAndNftTuple(parent_col, parent_nft) =
-				nft.owner
-			{
-				Children::<T>::remove((parent_col, parent_nft), (collection_id, nft_id));
+		let root_owner =
+			Self::lookup_root_owner(collection_id, nft_id).ok().map(|(owner, _)| owner);
+		for (index, ((cid, nid), _)) in nfts.into_iter().enumerate() {
+			Self::remove_nft_storage(cid, nid, root_owner.as_ref())?;
+			Children::<T>::remove_prefix((cid, nid), None);
+			// The caller burns the NFT itself in uniques, its descendants are burned here
+			if index > 0 {
+				pallet_uniques::Pallet::<T>::do_burn(cid, nid, |_, _| Ok(()))?;
 			}
 		}
 
-		Nfts::<T>::remove(collection_id, nft_id);
-
-		Resources::<T>::remove_prefix((collection_id, nft_id), None);
-
-		for ((child_collection_id, child_nft_id), _) in
-			Children::<T>::drain_prefix((collection_id, nft_id))
-		{
-			Self::nft_burn(child_collection_id, child_nft_id, max_recursions - 1)?;
-		}
-
-		// decrement nfts counter
-		Collections::<T>::try_mutate(collection_id, |collection| -> DispatchResult {
-			let collection = collection.as_mut().ok_or(Error::<T>::CollectionUnknown)?;
-			collection.nfts_count.saturating_dec();
-			Ok(())
-		})?;
-
 		Ok((collection_id, nft_id))
 	}
 
@@ -444,8 +795,8 @@ where
 		ensure!(parent.is_some(), Error::<T>::NoAvailableNftId); // <- is this error wrong?
 
 		let (root_owner, _root_nft) = Pallet::<T>::lookup_root_owner(collection_id, nft_id)?;
-		// Check ownership
-		ensure!(sender == root_owner, Error::<T>::NoPermission);
+		// Check ownership or approval
+		Self::ensure_owner_or_approved(&sender, &root_owner, collection_id, nft_id)?;
 		// Get NFT info
 		let mut sending_nft =
 			Nfts::<T>::get(collection_id, nft_id).ok_or(Error::<T>::NoAvailableNftId)?;
@@ -460,11 +811,14 @@ where
 
 		// Needs to be pending if the sending to an account or to a non-owned NFT
 		let mut approval_required = true;
+		// Root owner of the NFT and its descendants once sent
+		let new_root_owner;
 
 		// Prepare transfer
 		let new_owner_account = match new_owner.clone() {
 			AccountIdOrCollectionNftTuple::AccountId(id) => {
 				approval_required = false;
+				new_root_owner = id.clone();
 				id
 			},
 			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(cid, nid) => {
@@ -477,13 +831,20 @@ where
 				);
 				// Check if collection_id & nft_id are descendent of cid & nid
 				ensure!(
-					!Pallet::<T>::is_x_descendent_of_y(cid, nid, collection_id, nft_id),
+					!Pallet::<T>::is_x_descendent_of_y(cid, nid, collection_id, nft_id)?,
 					Error::<T>::CannotSendToDescendentOrSelf
 				);
 				let (recipient_root_owner, _root_nft) = Pallet::<T>::lookup_root_owner(cid, nid)?;
 				if recipient_root_owner == root_owner {
 					approval_required = false;
+				} else {
+					approval_required = Self::check_incoming_policy(
+						&recipient_root_owner,
+						&root_owner,
+						collection_id,
+					)?;
 				}
+				new_root_owner = recipient_root_owner;
 
 				// Convert to virtual account
 				Pallet::<T>::nft_to_account_id::<T::AccountId>(cid, nid)
@@ -491,17 +852,32 @@ where
 		};
 
 		sending_nft.owner = new_owner;
-		// Nfts::<T>::insert(collection_id, nft_id, sending_nft);
 
 		if approval_required {
-			Nfts::<T>::try_mutate_exists(collection_id, nft_id, |nft| -> DispatchResult {
-				if let Some(nft) = nft {
-					nft.pending = true;
-				}
-				Ok(())
-			})?;
+			// The NFT is recorded under its new owner, so that burning it while pending
+			// detaches it from the right parent
+			sending_nft.pending = true;
+			Nfts::<T>::insert(collection_id, nft_id, sending_nft);
+
+			// Record where the NFT came from, so it can be returned if rejected
+			let previous_owner = match parent.clone().and_then(|current_owner| {
+				Pallet::<T>::decode_nft_account_id::<T::AccountId>(current_owner)
+			}) {
+				Some((cid, nid)) => AccountIdOrCollectionNftTuple::CollectionAndNftTuple(cid, nid),
+				None => AccountIdOrCollectionNftTuple::AccountId(root_owner.clone()),
+			};
+			Self::set_pending_nft(collection_id, nft_id, root_owner.clone(), previous_owner);
+			PendingInbox::<T>::insert(
+				&new_root_owner,
+				PendingItem::Nft { collection_id, nft_id },
+				(),
+			);
 		} else {
+			// A pending NFT sent on to an owned NFT or an account is no longer pending
+			sending_nft.pending = false;
 			Nfts::<T>::insert(collection_id, nft_id, sending_nft);
+			Self::clear_pending_nft(collection_id, nft_id);
+			PendingInbox::<T>::remove(&root_owner, PendingItem::Nft { collection_id, nft_id });
 		}
 
 		if let Some(current_owner) = parent {
@@ -518,9 +894,16 @@ where
 		let new_owner_cid_nid =
 			Pallet::<T>::decode_nft_account_id::<T::AccountId>(new_owner_account.clone());
 		if let Some(new_owner_cid_nid) = new_owner_cid_nid {
-			Pallet::<T>::add_child(new_owner_cid_nid, (collection_id, nft_id));
+			Pallet::<T>::add_child(new_owner_cid_nid, (collection_id, nft_id), root_owner.clone())?;
+		} else if Self::nft_depth((collection_id, nft_id)) > 0 {
+			// Sent out of an NFT to an account, the NFT's tree moves up to the root
+			Self::set_subtree_depth((collection_id, nft_id), 0)?;
 		}
 
+		// The approval doesn't survive the transfer, whoever made it
+		Approvals::<T>::remove((collection_id, nft_id));
+		Self::move_root_owner_index(collection_id, nft_id, &root_owner, &new_root_owner)?;
+
 		Ok((new_owner_account, approval_required))
 	}
 
@@ -540,7 +923,7 @@ where
 			Nfts::<T>::get(collection_id, nft_id).ok_or(Error::<T>::NoAvailableNftId)?;
 
 		// Prepare acceptance
-		let new_owner_account = match new_owner {
+		let new_owner_account = match new_owner.clone() {
 			AccountIdOrCollectionNftTuple::AccountId(id) => id,
 			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(cid, nid) => {
 				// Check if NFT target exists
@@ -554,7 +937,7 @@ where
 
 				// Check if collection_id & nft_id are descendent of cid & nid
 				ensure!(
-					!Pallet::<T>::is_x_descendent_of_y(cid, nid, collection_id, nft_id),
+					!Pallet::<T>::is_x_descendent_of_y(cid, nid, collection_id, nft_id)?,
 					Error::<T>::CannotSendToDescendentOrSelf
 				);
 
@@ -566,12 +949,33 @@ where
 			},
 		};
 
-		Nfts::<T>::try_mutate(collection_id, nft_id, |nft| -> DispatchResult {
-			if let Some(nft) = nft {
-				nft.pending = false;
+		// Move the NFT to the parent it is accepted under, if that is not its current parent
+		let current_parent = pallet_uniques::Pallet::<T>::owner(collection_id, nft_id)
+			.and_then(Pallet::<T>::decode_nft_account_id::<T::AccountId>);
+		let new_parent = match new_owner {
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(cid, nid) => Some((cid, nid)),
+			AccountIdOrCollectionNftTuple::AccountId(_) => None,
+		};
+		if current_parent != new_parent {
+			if let Some(parent) = current_parent {
+				Self::remove_child(parent, (collection_id, nft_id));
 			}
-			Ok(())
-		})?;
+			match new_parent {
+				Some(parent) => Self::add_child(parent, (collection_id, nft_id), sender)?,
+				None => Self::set_subtree_depth((collection_id, nft_id), 0)?,
+			}
+		}
+
+		sending_nft.owner = new_owner.clone();
+		sending_nft.pending = false;
+		Nfts::<T>::insert(collection_id, nft_id, sending_nft);
+
+		Self::clear_pending_nft(collection_id, nft_id);
+		PendingInbox::<T>::remove(&root_owner, PendingItem::Nft { collection_id, nft_id });
+
+		if let AccountIdOrCollectionNftTuple::AccountId(id) = &new_owner {
+			Self::move_root_owner_index(collection_id, nft_id, &root_owner, id)?;
+		}
 
 		Ok((new_owner_account, collection_id, nft_id))
 	}
@@ -580,21 +984,66 @@ where
 		sender: T::AccountId,
 		collection_id: CollectionId,
 		nft_id: NftId,
-		max_recursions: u32,
-	) -> Result<(T::AccountId, CollectionId, NftId), DispatchError> {
-		// Look up root owner in Uniques to ensure permissions
-		let (root_owner, _root_nft) = Pallet::<T>::lookup_root_owner(collection_id, nft_id)?;
-
-		let nft = Nfts::<T>::get(collection_id, nft_id);
+	) -> Result<(T::AccountId, AccountIdOrCollectionNftTuple<T::AccountId>), DispatchError> {
+		let mut rejecting_nft = Self::ensure_rejectable(sender.clone(), collection_id, nft_id)?;
+
+		let pending_info = Self::clear_pending_nft(collection_id, nft_id)
+			.ok_or(Error::<T>::PendingNftOriginUnknown)?;
+		PendingInbox::<T>::remove(&sender, PendingItem::Nft { collection_id, nft_id });
+
+		// Return the NFT to its previous parent NFT, provided the sender still root-owns that
+		// parent, it is not a descendant of the rejected NFT, it has room for another child and
+		// the sender can pay the child deposit. Otherwise fall back to the sender's account.
+		let returned_to = match pending_info.previous_owner {
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(cid, nid)
+				if Self::can_return_under(
+					(cid, nid),
+					(collection_id, nft_id),
+					&pending_info.sender,
+				) =>
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(cid, nid),
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(..) =>
+				AccountIdOrCollectionNftTuple::AccountId(pending_info.sender.clone()),
+			account => account,
+		};
 
-		// Ensure NFT is pending (cannot reject non-pending NFT) and exists in Nfts storage
-		match nft {
-			None => return Err(Error::<T>::NoAvailableNftId.into()),
-			Some(nft) => ensure!(nft.pending, Error::<T>::CannotRejectNonPendingNft),
+		// Detach from the rejecting parent
+		if let Some(parent_account_id) = pallet_uniques::Pallet::<T>::owner(collection_id, nft_id) {
+			if let Some(parent) =
+				Pallet::<T>::decode_nft_account_id::<T::AccountId>(parent_account_id)
+			{
+				Self::remove_child(parent, (collection_id, nft_id));
+			}
 		}
 
-		// Check ownership
-		ensure!(sender == root_owner, Error::<T>::CannotRejectNonOwnedNft);
+		// Attach to the parent it is returned to
+		let returned_to_account = match returned_to.clone() {
+			AccountIdOrCollectionNftTuple::AccountId(id) => {
+				Self::move_root_owner_index(collection_id, nft_id, &sender, &id)?;
+				Self::set_subtree_depth((collection_id, nft_id), 0)?;
+				id
+			},
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(cid, nid) => {
+				Self::move_root_owner_index(collection_id, nft_id, &sender, &pending_info.sender)?;
+				Self::add_child((cid, nid), (collection_id, nft_id), pending_info.sender)?;
+				Pallet::<T>::nft_to_account_id::<T::AccountId>(cid, nid)
+			},
+		};
+
+		rejecting_nft.owner = returned_to.clone();
+		rejecting_nft.pending = false;
+		Nfts::<T>::insert(collection_id, nft_id, rejecting_nft);
+
+		Ok((returned_to_account, returned_to))
+	}
+
+	fn nft_reject_and_burn(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		max_recursions: u32,
+	) -> Result<(T::AccountId, CollectionId, NftId), DispatchError> {
+		Self::ensure_rejectable(sender.clone(), collection_id, nft_id)?;
 
 		// Get current owner, which we will use to remove the Children storage
 		if let Some(parent_account_id) = pallet_uniques::Pallet::<T>::owner(collection_id, nft_id) {
@@ -607,14 +1056,35 @@ where
 			}
 		}
 
-		// Get NFT info
-		let mut rejecting_nft =
-			Nfts::<T>::get(collection_id, nft_id).ok_or(Error::<T>::NoAvailableNftId)?;
-
 		Self::nft_burn(collection_id, nft_id, max_recursions)?;
 
 		Ok((sender, collection_id, nft_id))
 	}
+
+	fn nft_set_metadata(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		metadata: StringLimitOf<T>,
+		freeze: bool,
+	) -> DispatchResult {
+		let collection = Self::collections(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		ensure!(collection.issuer == sender, Error::<T>::NoPermission);
+		// Check NFT lock status
+		ensure!(!Pallet::<T>::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+		ensure!(!NftMetadataFrozen::<T>::get((collection_id, nft_id)), Error::<T>::MetadataFrozen);
+
+		Nfts::<T>::try_mutate_exists(collection_id, nft_id, |nft| -> DispatchResult {
+			let nft = nft.as_mut().ok_or(Error::<T>::NoAvailableNftId)?;
+			nft.metadata = metadata;
+			Ok(())
+		})?;
+		// Freezing is one-way, frozen metadata can never be changed again
+		if freeze {
+			NftMetadataFrozen::<T>::insert((collection_id, nft_id), true);
+		}
+		Ok(())
+	}
 }
 
 impl<T: Config> Locker<CollectionId, NftId> for Pallet<T>
@@ -678,9 +1148,60 @@ where
 		}
 	}
 
+	/// Returns the royalty of an NFT, falling back to the default royalty of its collection
+	/// when the NFT was minted without one
+	///
+	/// Parameters:
+	/// - `collection_id`: Collection ID of the NFT
+	/// - `nft_id`: NFT ID of the NFT
+	pub fn nft_royalty(collection_id: CollectionId, nft_id: NftId) -> Option<RoyaltyInfoOf<T>> {
+		let nft = Self::nfts(collection_id, nft_id)?;
+		nft.royalty.or_else(|| Self::collections(collection_id)?.royalty)
+	}
+
+	/// Returns the royalty of a new NFT, defaulting a royalty without any shares to a single
+	/// 100% share to the minter
+	///
+	/// Parameters:
+	/// - `sender`: Account minting the NFT
+	/// - `royalty`: Optional royalty the NFT is minted with
+	pub fn royalty_or_minter_share(
+		sender: &T::AccountId,
+		royalty: Option<RoyaltyInfoOf<T>>,
+	) -> Option<RoyaltyInfoOf<T>> {
+		royalty.map(|royalty| {
+			if royalty.shares.is_empty() {
+				let mut shares = BoundedRoyaltySharesOf::<T>::default();
+				// Only fails when `MaxRoyaltyRecipients` is zero, leaving the royalty without
+				// shares
+				let _ = shares.try_push((sender.clone(), Permill::one()));
+				RoyaltyInfo { shares }
+			} else {
+				royalty
+			}
+		})
+	}
+
+	/// Ensures the shares of a royalty do not add up to more than 100%
+	///
+	/// Parameters:
+	/// - `royalty`: Optional royalty of an NFT or collection
+	pub fn ensure_valid_royalty(royalty: &Option<RoyaltyInfoOf<T>>) -> DispatchResult {
+		if let Some(royalty) = royalty {
+			let total = royalty
+				.shares
+				.iter()
+				.try_fold(0u32, |total, (_, share)| total.checked_add(share.deconstruct()))
+				.ok_or(Error::<T>::RoyaltyExceedsTotal)?;
+			ensure!(total <= Permill::one().deconstruct(), Error::<T>::RoyaltyExceedsTotal);
+		}
+		Ok(())
+	}
+
 	/// Looks up the root owner of an NFT and returns a `Result` with an AccountId and
 	/// a tuple of the root `(CollectionId, NftId)`
 	/// or an `Error::<T>::NoAvailableNftId` in the case that the NFT is already burned
+	/// or an `Error::<T>::TooManyRecursions` if the NFT is nested deeper than `MaxRecursions`
 	///
 	/// Parameters:
 	/// - `collection_id`: Collection ID of the NFT to lookup the root owner
@@ -693,38 +1214,926 @@ where
 		collection_id: CollectionId,
 		nft_id: NftId,
 	) -> Result<(T::AccountId, (CollectionId, NftId)), Error<T>> {
-		let parent = pallet_uniques::Pallet::<T>::owner(collection_id, nft_id);
-		// Check if parent returns None which indicates the NFT is not available
-		parent.as_ref().ok_or(Error::<T>::NoAvailableNftId)?;
-		let owner = parent.unwrap();
-		match Self::decode_nft_account_id::<T::AccountId>(owner.clone()) {
-			None => Ok((owner, (collection_id, nft_id))),
-			Some((cid, nid)) => Pallet::<T>::lookup_root_owner(cid, nid),
+		let mut current = (collection_id, nft_id);
+		// An NFT at depth `MaxRecursions` is reached from its root in as many steps
+		for _ in 0..=T::MaxRecursions::get() {
+			// An owner of None indicates the NFT is not available
+			let owner = pallet_uniques::Pallet::<T>::owner(current.0, current.1)
+				.ok_or(Error::<T>::NoAvailableNftId)?;
+			match Self::decode_nft_account_id::<T::AccountId>(owner.clone()) {
+				None => return Ok((owner, current)),
+				Some(parent) => current = parent,
+			}
 		}
+		Err(Error::<T>::TooManyRecursions)
 	}
 
-	/// Add a child to a parent NFT
+	/// Add a child to a parent NFT, reserving the child deposit from `depositor`
 	///
 	/// Parameters:
 	/// - `parent`: Tuple of (CollectionId, NftId) of the parent NFT
 	/// - `child`: Tuple of (CollectionId, NftId) of the child NFT to be added
+	/// - `depositor`: Account the child deposit is reserved from
 	///
 	/// Output:
 	/// - Adding a `child` to the Children StorageMap of the `parent`
-	pub fn add_child(parent: (CollectionId, NftId), child: (CollectionId, NftId)) {
+	pub fn add_child(
+		parent: (CollectionId, NftId),
+		child: (CollectionId, NftId),
+		depositor: T::AccountId,
+	) -> DispatchResult {
+		Self::set_subtree_depth(child, Self::nft_depth(parent).saturating_add(1))?;
+		// Release any deposit left over from a previous parent before reserving a new one
+		Self::release_child_deposit(child);
+		let deposit = T::ChildDeposit::get();
+		<T as Config>::Currency::reserve(&depositor, deposit)?;
+		ChildDeposits::<T>::insert(child, (depositor, deposit));
+		ChildOrder::<T>::try_mutate(parent, |children| children.try_push(child))
+			.map_err(|_| Error::<T>::TooManyChildren)?;
+		// The child brings its own descendants along
+		let subtree_size = Self::descendant_count(child).saturating_add(1);
+		ensure!(Self::can_add_descendants(parent, subtree_size), Error::<T>::TooManyDescendants);
+		for ancestor in Self::self_and_ancestors(parent) {
+			DescendantCount::<T>::mutate(ancestor, |count| {
+				*count = count.saturating_add(subtree_size)
+			});
+		}
 		Children::<T>::insert((parent.0, parent.1), (child.0, child.1), ());
+		Ok(())
+	}
+
+	/// Whether `count` more descendants can be nested under an NFT without it or one of its
+	/// ancestors exceeding `MaxDescendants`
+	pub fn can_add_descendants(nft: (CollectionId, NftId), count: u32) -> bool {
+		Self::self_and_ancestors(nft).into_iter().all(|ancestor| {
+			Self::descendant_count(ancestor).saturating_add(count) <= T::MaxDescendants::get()
+		})
+	}
+
+	/// Lists an NFT followed by the NFTs above it, up to the NFT owned by an account and at most
+	/// `MaxRecursions` levels up, stopping early at an NFT that no longer exists
+	pub fn self_and_ancestors(nft: (CollectionId, NftId)) -> Vec<(CollectionId, NftId)> {
+		let mut nfts = Vec::new();
+		let mut next = Some(nft);
+		while let Some((collection_id, nft_id)) = next {
+			if nfts.len() > T::MaxRecursions::get() as usize {
+				break
+			}
+			next = match Self::nfts(collection_id, nft_id) {
+				Some(info) => {
+					nfts.push((collection_id, nft_id));
+					match info.owner {
+						AccountIdOrCollectionNftTuple::CollectionAndNftTuple(cid, nid) =>
+							Some((cid, nid)),
+						AccountIdOrCollectionNftTuple::AccountId(_) => None,
+					}
+				},
+				None => None,
+			};
+		}
+		nfts
+	}
+
+	/// Removes everything stored for an NFT except for its children, releasing the deposits
+	/// it holds, and decrements the NFT count of its collection
+	///
+	/// Parameters:
+	/// - `collection_id`: Collection of the NFT
+	/// - `nft_id`: NFT to remove
+	/// - `root_owner`: Root owner whose inventory and pending inbox list the NFT, if known
+	fn remove_nft_storage(
+		collection_id: CollectionId,
+		nft_id: NftId,
+		root_owner: Option<&T::AccountId>,
+	) -> DispatchResult {
+		// Let other pallets clean up state relying on the NFT while it can still be read
+		T::OnNftBurned::on_nft_burned(collection_id, nft_id);
+
+		// Remove from the inventory and pending inbox of the root owner
+		if let Some(root_owner) = root_owner {
+			NftsByRootOwner::<T>::remove(root_owner, (collection_id, nft_id));
+			for item in Self::pending_items(collection_id, nft_id) {
+				PendingInbox::<T>::remove(root_owner, item);
+			}
+		}
+
+		// Remove self from parent's Children storage
+		if let Some(nft) = Self::nfts(collection_id, nft_id) {
+			if let AccountIdOrCollectionNftTuple::CollectionAndNftTuple(parent_col, parent_nft) =
+				nft.owner
+			{
+				Self::remove_child((parent_col, parent_nft), (collection_id, nft_id));
+			}
+		}
+		Self::release_child_deposit((collection_id, nft_id));
+
+		Nfts::<T>::remove(collection_id, nft_id);
+
+		Self::clear_pending_nft(collection_id, nft_id);
+
+		NftMetadataFrozen::<T>::remove((collection_id, nft_id));
+		NftDepth::<T>::remove((collection_id, nft_id));
+		DescendantCount::<T>::remove((collection_id, nft_id));
+		Lock::<T>::remove((collection_id, nft_id));
+		Approvals::<T>::remove((collection_id, nft_id));
+
+		// Remove opt-outs from the collection's shared resources
+		for resource_id in CollectionResources::<T>::iter_key_prefix(collection_id) {
+			CollectionResourceOptOuts::<T>::remove((collection_id, resource_id, nft_id));
+		}
+
+		// Remove resources and properties, releasing their deposits
+		Self::remove_nft_items(collection_id, nft_id, u32::MAX);
+		PendingResourceReplacements::<T>::remove_prefix((collection_id, nft_id), None);
+		Priorities::<T>::remove_prefix((collection_id, nft_id), None);
+		EquippableBases::<T>::remove_prefix((collection_id, nft_id), None);
+		EquippableSlots::<T>::remove_prefix((collection_id, nft_id), None);
+		NextResourceId::<T>::remove(collection_id, nft_id);
+
+		ChildOrder::<T>::remove((collection_id, nft_id));
+
+		// decrement nfts counter
+		Collections::<T>::try_mutate(collection_id, |collection| -> DispatchResult {
+			let collection = collection.as_mut().ok_or(Error::<T>::CollectionUnknown)?;
+			collection.nfts_count.saturating_dec();
+			Ok(())
+		})
+	}
+
+	/// Removes at most `limit` resources, properties and owner properties of an NFT, in that
+	/// order, releasing their deposits: to the issuer for resources and the properties it wrote,
+	/// to their writers for the others. Removed items are gone from storage, so a later call
+	/// resumes where this one stopped.
+	///
+	/// Parameters:
+	/// - `collection_id`: Collection of the NFT
+	/// - `nft_id`: NFT whose resources and properties are removed
+	/// - `limit`: Maximum number of items to remove
+	///
+	/// Output:
+	/// - The number of items removed, below `limit` only if none are left
+	pub fn remove_nft_items(collection_id: CollectionId, nft_id: NftId, limit: u32) -> u32 {
+		let mut left = limit as usize;
+
+		let resource_ids: Vec<ResourceId> =
+			Resources::<T>::iter_key_prefix((collection_id, nft_id)).take(left).collect();
+		left -= resource_ids.len();
+		let mut released_deposit =
+			T::ResourceDeposit::get().saturating_mul((resource_ids.len() as u32).into());
+		for resource_id in resource_ids {
+			if let Some(resource) = Resources::<T>::take((collection_id, nft_id, resource_id)) {
+				if let Some((base_id, slot_id)) = Self::resource_slot(&resource.resource) {
+					EquippableSlots::<T>::remove((
+						collection_id,
+						nft_id,
+						resource_id,
+						base_id,
+						slot_id,
+					));
+				}
+			}
+			PendingResourceReplacements::<T>::remove((collection_id, nft_id, resource_id));
+			Priorities::<T>::remove((collection_id, nft_id, resource_id));
+		}
+
+		let keys: Vec<KeyLimitOf<T>> =
+			Properties::<T>::iter_key_prefix((collection_id, Some(nft_id)))
+				.take(left)
+				.collect();
+		left -= keys.len();
+		for key in keys {
+			let value = Properties::<T>::take((collection_id, Some(nft_id), &key));
+			// Properties written by other accounts than the issuer are paid for by their writers
+			match PropertyDeposits::<T>::take((collection_id, Some(nft_id), &key)) {
+				Some((depositor, deposit)) => {
+					<T as Config>::Currency::unreserve(&depositor, deposit);
+				},
+				None =>
+					if let Some(value) = value {
+						released_deposit =
+							released_deposit.saturating_add(Self::property_deposit(&key, &value));
+					},
+			}
+		}
+		if let Some(collection) = Self::collections(collection_id) {
+			Self::release_collection_deposit(collection_id, &collection.issuer, released_deposit);
+		}
+
+		let keys: Vec<KeyLimitOf<T>> =
+			OwnerProperties::<T>::iter_key_prefix((collection_id, nft_id))
+				.take(left)
+				.collect();
+		left -= keys.len();
+		for key in keys {
+			OwnerProperties::<T>::remove((collection_id, nft_id, &key));
+			Self::release_owner_property_deposit((collection_id, nft_id, &key));
+		}
+
+		(limit as usize - left) as u32
+	}
+
+	/// Whether an NFT still has resources, properties or owner properties
+	fn has_nft_items(collection_id: CollectionId, nft_id: NftId) -> bool {
+		Resources::<T>::iter_key_prefix((collection_id, nft_id)).next().is_some() ||
+			Properties::<T>::iter_key_prefix((collection_id, Some(nft_id))).next().is_some() ||
+			OwnerProperties::<T>::iter_key_prefix((collection_id, nft_id)).next().is_some()
+	}
+
+	/// Lists an NFT and its descendants, parents ahead of their children, each with its depth
+	/// below the NFT
+	///
+	/// The tree is walked without recursion, failing with `TooManyRecursions` if it is more
+	/// than `MaxRecursions` levels deep or `TooManyDescendants` if it holds more than
+	/// `MaxDescendants` NFTs below the NFT, so callers can check it all before any change.
+	///
+	/// Parameters:
+	/// - `nft`: Tuple of (CollectionId, NftId) of the NFT at the top of the tree
+	pub fn subtree(
+		nft: (CollectionId, NftId),
+	) -> Result<Vec<((CollectionId, NftId), u32)>, DispatchError> {
+		let mut nfts = Vec::new();
+		let mut stack = sp_std::vec![(nft, 0u32)];
+		while let Some((nft, depth)) = stack.pop() {
+			ensure!(depth <= T::MaxRecursions::get(), Error::<T>::TooManyRecursions);
+			ensure!(
+				nfts.len() <= T::MaxDescendants::get() as usize,
+				Error::<T>::TooManyDescendants
+			);
+			nfts.push((nft, depth));
+			for child in Children::<T>::iter_key_prefix(nft) {
+				stack.push((child, depth + 1));
+			}
+		}
+		Ok(nfts)
+	}
+
+	/// Sets the depth of an NFT and updates the depths of its descendants accordingly, failing
+	/// with `TooManyRecursions` before any change if one of them would end up deeper than
+	/// `MaxRecursions`. The tree holds at most `MaxDescendants` NFTs below the NFT, which
+	/// `subtree_weight` charges for.
+	///
+	/// Parameters:
+	/// - `nft`: Tuple of (CollectionId, NftId) of the NFT at the top of the tree
+	/// - `depth`: New depth of the NFT, 0 if owned by an account
+	pub fn set_subtree_depth(nft: (CollectionId, NftId), depth: u32) -> DispatchResult {
+		let mut depths = Self::subtree(nft)?;
+		for (_, nft_depth) in depths.iter_mut() {
+			*nft_depth = nft_depth.saturating_add(depth);
+			ensure!(*nft_depth <= T::MaxRecursions::get(), Error::<T>::TooManyRecursions);
+		}
+
+		for (nft, depth) in depths {
+			if depth == 0 {
+				NftDepth::<T>::remove(nft);
+			} else {
+				NftDepth::<T>::insert(nft, depth);
+			}
+		}
+		Ok(())
+	}
+
+	/// Weight of `walks` walks up a tree of NFTs, each at most `MaxRecursions` levels deep
+	pub fn traversal_weight(walks: u64) -> Weight {
+		T::DbWeight::get()
+			.reads(T::MaxRecursions::get() as u64 + 1)
+			.saturating_mul(walks)
+	}
+
+	/// Weight of moving the tree below an NFT, at most `MaxDescendants` NFTs, to the inventory
+	/// of another root owner and updating their depths, walking the tree once for each
+	pub fn subtree_weight() -> Weight {
+		T::DbWeight::get()
+			.reads_writes(6, 5)
+			.saturating_mul(T::MaxDescendants::get() as u64 + 1)
+	}
+
+	/// Weight of counting the resources of an NFT or collection, at most `MaxResourcesPerNft`
+	pub fn resources_count_weight() -> Weight {
+		T::DbWeight::get().reads(T::MaxResourcesPerNft::get() as u64)
+	}
+
+	/// Weight of counting the properties of an NFT, at most `MaxPropertiesPerNft`
+	pub fn properties_count_weight() -> Weight {
+		T::DbWeight::get().reads(T::MaxPropertiesPerNft::get() as u64)
+	}
+
+	/// Weight of removing `items` resources or properties of an NFT, releasing their deposits
+	pub fn item_removal_weight(items: u64) -> Weight {
+		(1_000 + T::DbWeight::get().reads_writes(3, 4)).saturating_mul(items)
+	}
+
+	/// Weight of deleting an NFT queued by `nft_burn_lazily` once its resources and properties
+	/// are removed, queueing up to `MaxChildrenPerNft` children in turn
+	pub fn lazy_burn_weight() -> Weight {
+		(10_000 + T::DbWeight::get().reads_writes(16, 16))
+			.saturating_add(Self::item_removal_weight(T::MaxResourcesPerNft::get() as u64))
+			.saturating_add(
+				T::DbWeight::get()
+					.reads_writes(4, 5)
+					.saturating_mul(T::MaxChildrenPerNft::get() as u64),
+			)
+	}
+
+	/// Weight of burning `nfts` NFTs along with their resources and properties, each NFT having
+	/// at most `MaxResourcesPerNft` resources of its own and as many shared by its collection,
+	/// and `MaxPropertiesPerNft` properties set by the issuer and as many set by the owner
+	pub fn burn_weight(nfts: u64) -> Weight {
+		let items = T::MaxResourcesPerNft::get()
+			.saturating_add(T::MaxPropertiesPerNft::get())
+			.saturating_mul(2);
+		(10_000 + T::DbWeight::get().reads_writes(16, 16))
+			.saturating_add(Self::item_removal_weight(items as u64))
+			.saturating_mul(nfts)
+	}
+
+	/// Changes the order in which the children of an NFT are listed
+	///
+	/// Parameters:
+	/// - `sender`: Root owner of the parent NFT
+	/// - `collection_id`: Collection of the parent NFT
+	/// - `nft_id`: Parent NFT
+	/// - `order`: Every child of the parent NFT exactly once, in the new order
+	pub fn children_reorder(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		order: BoundedVec<(CollectionId, NftId), T::MaxChildrenPerNft>,
+	) -> DispatchResult {
+		let (root_owner, _) = Self::lookup_root_owner(collection_id, nft_id)?;
+		ensure!(sender == root_owner, Error::<T>::NoPermission);
+
+		let mut current = Self::child_order((collection_id, nft_id)).into_inner();
+		let mut requested = order.clone().into_inner();
+		current.sort();
+		requested.sort();
+		ensure!(current == requested, Error::<T>::InvalidChildOrder);
+
+		ChildOrder::<T>::insert((collection_id, nft_id), order);
+		Ok(())
+	}
+
+	/// Approves an operator to send or burn an NFT, replacing any previous approval
+	///
+	/// Parameters:
+	/// - `sender`: Root owner of the NFT
+	/// - `collection_id`: Collection of the NFT
+	/// - `nft_id`: NFT to approve the operator for
+	/// - `operator`: Account approved to send or burn the NFT
+	/// - `expiry`: Last block the approval is valid at, if any
+	pub fn nft_approve(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		operator: T::AccountId,
+		expiry: Option<T::BlockNumber>,
+	) -> DispatchResult {
+		let (root_owner, _) = Self::lookup_root_owner(collection_id, nft_id)?;
+		ensure!(sender == root_owner, Error::<T>::NoPermission);
+
+		Approvals::<T>::insert((collection_id, nft_id), (operator, expiry));
+		Ok(())
+	}
+
+	/// Cancels the approval of the operator of an NFT
+	///
+	/// Parameters:
+	/// - `sender`: Root owner of the NFT
+	/// - `collection_id`: Collection of the NFT
+	/// - `nft_id`: NFT to cancel the approval of
+	pub fn nft_cancel_approval(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+	) -> DispatchResult {
+		let (root_owner, _) = Self::lookup_root_owner(collection_id, nft_id)?;
+		ensure!(sender == root_owner, Error::<T>::NoPermission);
+		ensure!(
+			Approvals::<T>::contains_key((collection_id, nft_id)),
+			Error::<T>::ApprovalNotFound
+		);
+
+		Approvals::<T>::remove((collection_id, nft_id));
+		Ok(())
+	}
+
+	/// Ensures `sender` may send or burn an NFT root-owned by `root_owner`: as the root owner
+	/// itself, as an operator of all its NFTs or as the unexpired approved operator of the NFT
+	pub fn ensure_owner_or_approved(
+		sender: &T::AccountId,
+		root_owner: &T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+	) -> DispatchResult {
+		if sender == root_owner || OperatorApprovals::<T>::contains_key(root_owner, sender) {
+			return Ok(())
+		}
+		let now = <frame_system::Pallet<T>>::block_number();
+		match Approvals::<T>::get((collection_id, nft_id)) {
+			Some((operator, expiry))
+				if operator == *sender && expiry.map_or(true, |expiry| now <= expiry) =>
+				Ok(()),
+			_ => Err(Error::<T>::NoPermission.into()),
+		}
+	}
+
+	/// Moves an NFT and all of its descendants, along with their pending items, from the
+	/// inventory of root owner `from` to the inventory of root owner `to`, clearing their
+	/// approvals. The tree is at most `MaxDescendants` NFTs below the NFT, which callers charge
+	/// for with `subtree_weight`, and fails the move before any change if it is larger or more
+	/// than `MaxRecursions` levels deep.
+	///
+	/// Parameters:
+	/// - `collection_id`: Collection of the NFT at the top of the moved tree
+	/// - `nft_id`: NFT at the top of the moved tree
+	/// - `from`: Previous root owner of the tree
+	/// - `to`: New root owner of the tree
+	pub fn move_root_owner_index(
+		collection_id: CollectionId,
+		nft_id: NftId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+	) -> DispatchResult {
+		if from == to {
+			return Ok(())
+		}
+		for (nft, _) in Self::subtree((collection_id, nft_id))? {
+			NftsByRootOwner::<T>::remove(from, nft);
+			NftsByRootOwner::<T>::insert(to, nft, ());
+			// Approvals made by the previous root owner no longer apply
+			Approvals::<T>::remove(nft);
+			for item in Self::pending_items(nft.0, nft.1) {
+				PendingInbox::<T>::remove(from, item);
+				PendingInbox::<T>::insert(to, item, ());
+			}
+		}
+		Ok(())
+	}
+
+	/// Get a page of the NFTs root-owned by an account, either directly or nested in NFTs it
+	/// owns
+	///
+	/// Parameters:
+	/// - `account`: Root owner of the NFTs
+	/// - `start`: Number of NFTs to skip
+	/// - `count`: Maximum number of NFTs to return
+	///
+	/// NFTs are returned in storage order, which is stable as long as the inventory doesn't
+	/// change between pages.
+	pub fn nfts_owned_by(
+		account: &T::AccountId,
+		start: u32,
+		count: u32,
+	) -> Vec<(CollectionId, NftId)> {
+		NftsByRootOwner::<T>::iter_key_prefix(account)
+			.skip(start as usize)
+			.take(count as usize)
+			.collect()
+	}
+
+	/// Get the items of an NFT awaiting the decision of its root owner
+	///
+	/// Parameters:
+	/// - `collection_id`: Collection of the NFT
+	/// - `nft_id`: NFT the items belong to
+	///
+	/// Output:
+	/// - The pending NFT itself, its pending resource additions, removals and replacements
+	pub fn pending_items(collection_id: CollectionId, nft_id: NftId) -> Vec<PendingItem> {
+		let mut items = Vec::new();
+		if PendingNfts::<T>::contains_key(collection_id, nft_id) {
+			items.push(PendingItem::Nft { collection_id, nft_id });
+		}
+		for resource in Resources::<T>::iter_prefix_values((collection_id, nft_id)) {
+			let resource_id = resource.id;
+			if resource.pending {
+				items.push(PendingItem::ResourceAddition { collection_id, nft_id, resource_id });
+			}
+			if resource.pending_removal {
+				items.push(PendingItem::ResourceRemoval { collection_id, nft_id, resource_id });
+			}
+		}
+		for resource_id in
+			PendingResourceReplacements::<T>::iter_key_prefix((collection_id, nft_id))
+		{
+			items.push(PendingItem::ResourceReplacement { collection_id, nft_id, resource_id });
+		}
+		items
+	}
+
+	/// Get a page of the items awaiting the decision of an account, as the root owner of the
+	/// NFTs they belong to
+	///
+	/// Parameters:
+	/// - `account`: Root owner the items await
+	/// - `start`: Number of items to skip
+	/// - `count`: Maximum number of items to return
+	pub fn pending_inbox_of(account: &T::AccountId, start: u32, count: u32) -> Vec<PendingItem> {
+		PendingInbox::<T>::iter_key_prefix(account)
+			.skip(start as usize)
+			.take(count as usize)
+			.collect()
 	}
 
-	/// Remove a child from a parent NFT
+	/// Remove a child from a parent NFT, releasing the child deposit
 	///
 	/// Parameters:
 	/// - `parent`: Tuple of (CollectionId, NftId) of the parent NFT
 	/// - `child`: Tuple of (CollectionId, NftId) of the child NFT to be removed
 	///
 	/// Output:
-	/// - Removing a `child` from the Children StorageMap of the `parent`
+	/// - Removing a `child` from the Children StorageMap and child order of the `parent`
 	pub fn remove_child(parent: (CollectionId, NftId), child: (CollectionId, NftId)) {
-		Children::<T>::remove((parent.0, parent.1), (child.0, child.1));
+		if Children::<T>::take((parent.0, parent.1), (child.0, child.1)).is_some() {
+			// The child takes its own descendants along
+			let subtree_size = Self::descendant_count(child).saturating_add(1);
+			for ancestor in Self::self_and_ancestors(parent) {
+				DescendantCount::<T>::mutate_exists(ancestor, |count| {
+					*count = count
+						.map(|count| count.saturating_sub(subtree_size))
+						.filter(|count| *count > 0)
+				});
+			}
+		}
+		ChildOrder::<T>::mutate_exists(parent, |children| {
+			if let Some(list) = children {
+				list.retain(|listed| *listed != child);
+				if list.is_empty() {
+					*children = None;
+				}
+			}
+		});
+		Self::release_child_deposit(child);
+	}
+
+	/// Releases the deposit reserved for `child` being owned by an NFT, if any
+	fn release_child_deposit(child: (CollectionId, NftId)) {
+		if let Some((depositor, deposit)) = ChildDeposits::<T>::take(child) {
+			<T as Config>::Currency::unreserve(&depositor, deposit);
+		}
+	}
+
+	/// Reserves a resource or property deposit from the collection issuer
+	fn reserve_collection_deposit(
+		collection_id: CollectionId,
+		issuer: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		<T as Config>::Currency::reserve(issuer, amount)?;
+		CollectionDeposits::<T>::mutate(collection_id, |total| {
+			*total = total.saturating_add(amount);
+		});
+		Ok(())
+	}
+
+	/// Releases a resource or property deposit back to the collection issuer, never releasing
+	/// more than what was reserved for the collection
+	fn release_collection_deposit(
+		collection_id: CollectionId,
+		issuer: &T::AccountId,
+		amount: BalanceOf<T>,
+	) {
+		let amount = CollectionDeposits::<T>::mutate(collection_id, |total| {
+			let amount = amount.min(*total);
+			*total = total.saturating_sub(amount);
+			amount
+		});
+		<T as Config>::Currency::unreserve(issuer, amount);
+	}
+
+	/// Ensures `sender` may write (set, update or remove) a property, according to the
+	/// permission of its key
+	///
+	/// Parameters:
+	/// - `sender`: Account writing the property
+	/// - `issuer`: Issuer of the collection
+	/// - `collection_id`: Collection of the property
+	/// - `maybe_nft_id`: NFT of the property, `None` for collection-level properties
+	/// - `key`: Key of the property
+	/// - `exists`: Whether the property is already set
+	fn ensure_property_writable(
+		sender: &T::AccountId,
+		issuer: &T::AccountId,
+		collection_id: CollectionId,
+		maybe_nft_id: Option<NftId>,
+		key: &KeyLimitOf<T>,
+		exists: bool,
+	) -> DispatchResult {
+		let permission = PropertyPermissions::<T>::get(collection_id, key);
+		if permission == PropertyPermission::Immutable {
+			ensure!(!exists, Error::<T>::PropertyImmutable);
+		}
+
+		match maybe_nft_id {
+			// Collection-level properties can only be written by the issuer
+			None => ensure!(sender == issuer, Error::<T>::NoPermission),
+			Some(nft_id) => {
+				// Check NFT lock status
+				ensure!(
+					!Pallet::<T>::is_locked(collection_id, nft_id),
+					pallet_uniques::Error::<T>::Locked
+				);
+				let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id)?;
+				let allowed = match permission {
+					PropertyPermission::IssuerOnly | PropertyPermission::Immutable =>
+						sender == issuer && root_owner == *issuer,
+					PropertyPermission::OwnerOnly => *sender == root_owner,
+					PropertyPermission::IssuerAndOwner => sender == issuer || *sender == root_owner,
+				};
+				ensure!(allowed, Error::<T>::NoPermission);
+			},
+		}
+		Ok(())
+	}
+
+	/// Ensures the base, parts and slot referenced by a resource exist in `T::Bases`
+	fn ensure_valid_resource(resource: &ResourceTypeOf<T>) -> DispatchResult {
+		let ensure_slot = |(base_id, slot_id): (BaseId, SlotId)| -> DispatchResult {
+			ensure!(T::Bases::base_exists(base_id), Error::<T>::BaseDoesntExist);
+			ensure!(T::Bases::is_slot_part(base_id, slot_id), Error::<T>::NotASlotPart);
+			Ok(())
+		};
+		match resource {
+			ResourceTypes::Basic(_) => (),
+			ResourceTypes::Composable(r) => {
+				ensure!(T::Bases::base_exists(r.base), Error::<T>::BaseDoesntExist);
+				for part_id in r.parts.iter() {
+					ensure!(T::Bases::part_exists(r.base, *part_id), Error::<T>::PartDoesntExist);
+				}
+				if let Some(slot) = r.slot {
+					ensure_slot(slot)?;
+				}
+			},
+			ResourceTypes::Slot(r) => ensure_slot((r.base, r.slot))?,
+		}
+		Ok(())
+	}
+
+	/// The base a resource makes its NFT able to equip items into, if any
+	fn resource_base(resource: &ResourceTypeOf<T>) -> Option<BaseId> {
+		match resource {
+			ResourceTypes::Composable(r) => Some(r.base),
+			_ => None,
+		}
+	}
+
+	/// The base and slot a resource makes its NFT equippable into, if any
+	fn resource_slot(resource: &ResourceTypeOf<T>) -> Option<(BaseId, SlotId)> {
+		match resource {
+			ResourceTypes::Basic(_) => None,
+			ResourceTypes::Composable(r) => r.slot,
+			ResourceTypes::Slot(r) => Some((r.base, r.slot)),
+		}
+	}
+
+	/// Counts one more resource of an NFT using a base
+	fn retain_base(collection_id: CollectionId, nft_id: NftId, base_id: BaseId) {
+		EquippableBases::<T>::mutate((collection_id, nft_id, base_id), |count| {
+			*count = Some(count.unwrap_or_default().saturating_add(1));
+		});
+	}
+
+	/// Counts one less resource of an NFT using a base, returning whether the base is no longer
+	/// used by any resource
+	fn release_base(collection_id: CollectionId, nft_id: NftId, base_id: BaseId) -> bool {
+		let remaining = Self::equippable_bases((collection_id, nft_id, base_id))
+			.unwrap_or_default()
+			.saturating_sub(1);
+		if remaining == 0 {
+			EquippableBases::<T>::remove((collection_id, nft_id, base_id));
+		} else {
+			EquippableBases::<T>::insert((collection_id, nft_id, base_id), remaining);
+		}
+		remaining == 0
+	}
+
+	/// Records the equippable base and slot of a resource
+	fn add_resource_indexes(
+		collection_id: CollectionId,
+		nft_id: NftId,
+		resource_id: ResourceId,
+		resource: &ResourceTypeOf<T>,
+	) {
+		if let Some(base_id) = Self::resource_base(resource) {
+			Self::retain_base(collection_id, nft_id, base_id);
+		}
+		if let Some((base_id, slot_id)) = Self::resource_slot(resource) {
+			EquippableSlots::<T>::insert(
+				(collection_id, nft_id, resource_id, base_id, slot_id),
+				(),
+			);
+		}
+	}
+
+	/// Removes the equippable base and slot of a resource, then notifies
+	/// `T::OnResourceRemoved` so anything equipped through it gets unequipped
+	///
+	/// The `EquippableBases` entry of a composable resource is kept while another composable
+	/// resource of the NFT still uses the same base.
+	fn remove_resource_indexes(
+		collection_id: CollectionId,
+		nft_id: NftId,
+		resource_id: ResourceId,
+		resource: &ResourceTypeOf<T>,
+	) {
+		let released_base = Self::resource_base(resource)
+			.filter(|base_id| Self::release_base(collection_id, nft_id, *base_id));
+		let slots: Vec<(BaseId, SlotId)> = Self::resource_slot(resource).into_iter().collect();
+		for (base_id, slot_id) in &slots {
+			EquippableSlots::<T>::remove((collection_id, nft_id, resource_id, base_id, slot_id));
+		}
+		T::OnResourceRemoved::on_resource_removed(
+			collection_id,
+			nft_id,
+			resource_id,
+			&slots,
+			released_base,
+		);
+	}
+
+	/// Replaces the content of a resource, keeping its ID, priority and acceptance state
+	///
+	/// Items stay equipped through the resource as long as the new resource provides the same
+	/// base and slot.
+	fn swap_resource(
+		collection_id: CollectionId,
+		nft_id: NftId,
+		resource_id: ResourceId,
+		resource: ResourceTypeOf<T>,
+	) {
+		let mut info = match Resources::<T>::get((collection_id, nft_id, resource_id)) {
+			Some(info) => info,
+			None => return,
+		};
+
+		let (old_base, new_base) =
+			(Self::resource_base(&info.resource), Self::resource_base(&resource));
+		let mut released_base = None;
+		if old_base != new_base {
+			if let Some(base_id) = new_base {
+				Self::retain_base(collection_id, nft_id, base_id);
+			}
+			released_base =
+				old_base.filter(|base_id| Self::release_base(collection_id, nft_id, *base_id));
+		}
+
+		let (old_slot, new_slot) =
+			(Self::resource_slot(&info.resource), Self::resource_slot(&resource));
+		let mut removed_slots = Vec::new();
+		if old_slot != new_slot {
+			if let Some((base_id, slot_id)) = old_slot {
+				EquippableSlots::<T>::remove((
+					collection_id,
+					nft_id,
+					resource_id,
+					base_id,
+					slot_id,
+				));
+				removed_slots.push((base_id, slot_id));
+			}
+			if let Some((base_id, slot_id)) = new_slot {
+				EquippableSlots::<T>::insert(
+					(collection_id, nft_id, resource_id, base_id, slot_id),
+					(),
+				);
+			}
+		}
+
+		info.resource = resource;
+		Resources::<T>::insert((collection_id, nft_id, resource_id), info);
+
+		if released_base.is_some() || !removed_slots.is_empty() {
+			T::OnResourceRemoved::on_resource_removed(
+				collection_id,
+				nft_id,
+				resource_id,
+				&removed_slots,
+				released_base,
+			);
+		}
+	}
+
+	/// Deletes a resource of an NFT along with the equip indexes, priority and pending inbox
+	/// entries it populated
+	pub fn delete_resource(collection_id: CollectionId, nft_id: NftId, resource_id: ResourceId) {
+		let resource = match Resources::<T>::take((collection_id, nft_id, resource_id)) {
+			Some(resource) => resource,
+			None => return,
+		};
+		PendingResourceReplacements::<T>::remove((collection_id, nft_id, resource_id));
+		if let Ok((root_owner, _)) = Self::lookup_root_owner(collection_id, nft_id) {
+			for item in [
+				PendingItem::ResourceAddition { collection_id, nft_id, resource_id },
+				PendingItem::ResourceRemoval { collection_id, nft_id, resource_id },
+				PendingItem::ResourceReplacement { collection_id, nft_id, resource_id },
+			] {
+				PendingInbox::<T>::remove(&root_owner, item);
+			}
+		}
+		Priorities::<T>::remove((collection_id, nft_id, resource_id));
+		Self::remove_resource_indexes(collection_id, nft_id, resource_id, &resource.resource);
+	}
+
+	/// Ensures a property value matches the type declared for its key in the collection schema
+	///
+	/// Keys without a declared type accept any value.
+	fn ensure_property_matches_schema(
+		collection_id: CollectionId,
+		key: &KeyLimitOf<T>,
+		value: &ValueLimitOf<T>,
+	) -> DispatchResult {
+		let property_type = match PropertySchemas::<T>::get(collection_id, key) {
+			Some(property_type) => property_type,
+			None => return Ok(()),
+		};
+		let input = &mut &value[..];
+		let valid = match property_type {
+			PropertyType::U32 => u32::decode_all(input).is_ok(),
+			PropertyType::I64 => i64::decode_all(input).is_ok(),
+			PropertyType::Bool => bool::decode_all(input).is_ok(),
+			PropertyType::String => sp_std::str::from_utf8(value).is_ok(),
+			PropertyType::AccountId => T::AccountId::decode_all(input).is_ok(),
+			PropertyType::NftReference => <(CollectionId, NftId)>::decode_all(input).is_ok(),
+		};
+		ensure!(valid, Error::<T>::PropertyTypeMismatch);
+		Ok(())
+	}
+
+	/// Get the property schema of a collection as (key, type) pairs
+	pub fn collection_property_schema(collection_id: CollectionId) -> Vec<(Vec<u8>, PropertyType)> {
+		PropertySchemas::<T>::iter_prefix(collection_id)
+			.map(|(key, property_type)| (key.into_inner(), property_type))
+			.collect()
+	}
+
+	/// Get the resources an NFT displays: its own accepted resources followed by the shared
+	/// resources of its collection it didn't opt out of, each ordered by resource ID
+	pub fn nft_effective_resources(
+		collection_id: CollectionId,
+		nft_id: NftId,
+	) -> Vec<EffectiveResource<StringLimitOf<T>, BoundedVec<PartId, T::PartsLimit>>> {
+		let mut own: Vec<_> = Resources::<T>::iter_prefix_values((collection_id, nft_id))
+			.filter(|res| !res.pending)
+			.collect();
+		own.sort_by_key(|res| res.id);
+		let mut shared: Vec<_> = CollectionResources::<T>::iter_prefix(collection_id)
+			.filter(|(resource_id, _)| {
+				!CollectionResourceOptOuts::<T>::contains_key((collection_id, resource_id, nft_id))
+			})
+			.collect();
+		shared.sort_by_key(|(resource_id, _)| *resource_id);
+
+		own.into_iter()
+			.map(EffectiveResource::Nft)
+			.chain(
+				shared
+					.into_iter()
+					.map(|(id, resource)| EffectiveResource::Collection { id, resource }),
+			)
+			.collect()
+	}
+
+	fn ensure_owner_property_writable(
+		sender: &T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+	) -> DispatchResult {
+		ensure!(Collections::<T>::contains_key(collection_id), Error::<T>::CollectionUnknown);
+		// Check NFT lock status
+		ensure!(!Pallet::<T>::is_locked(collection_id, nft_id), pallet_uniques::Error::<T>::Locked);
+		let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id)?;
+		ensure!(*sender == root_owner, Error::<T>::NoPermission);
+		Ok(())
+	}
+
+	/// Releases the deposit of a property back to the account that reserved it, or to the
+	/// collection issuer if the issuer wrote it
+	fn release_property_deposit(
+		issuer: &T::AccountId,
+		property: (CollectionId, Option<NftId>, &KeyLimitOf<T>),
+		value: &ValueLimitOf<T>,
+	) {
+		match PropertyDeposits::<T>::take(property) {
+			Some((depositor, deposit)) => {
+				<T as Config>::Currency::unreserve(&depositor, deposit);
+			},
+			None => Self::release_collection_deposit(
+				property.0,
+				issuer,
+				Self::property_deposit(property.2, value),
+			),
+		}
+	}
+
+	/// Releases the deposit of an owner property back to the account that reserved it
+	fn release_owner_property_deposit(property: (CollectionId, NftId, &KeyLimitOf<T>)) {
+		if let Some((depositor, deposit)) = OwnerPropertyDeposits::<T>::take(property) {
+			<T as Config>::Currency::unreserve(&depositor, deposit);
+		}
+	}
+
+	/// Deposit required to store a property of the given key and value
+	pub fn property_deposit(key: &KeyLimitOf<T>, value: &ValueLimitOf<T>) -> BalanceOf<T> {
+		T::PropertyDepositPerByte::get().saturating_mul(((key.len() + value.len()) as u32).into())
 	}
 
 	/// Check whether a NFT is descends from a suspected parent NFT
@@ -736,37 +2145,28 @@ where
 	/// - `parent_collection_id`: Collection ID of the NFT to lookup the root owner
 	/// - `parent_nft_id`: NFT ID that is to be looked up for the root owner
 	/// Output:
-	/// - `bool`
+	/// - `Result<bool, Error<T>>`, failing with `TooManyRecursions` if the child is nested
+	/// deeper than `MaxRecursions`
 	pub fn is_x_descendent_of_y(
 		child_collection_id: CollectionId,
 		child_nft_id: NftId,
 		parent_collection_id: CollectionId,
 		parent_nft_id: NftId,
-	) -> bool {
-		let mut found_child = false;
-
-		let parent = pallet_uniques::Pallet::<T>::owner(child_collection_id, child_nft_id);
-		// Check if parent returns None which indicates the NFT is not available
-		if parent.is_none() {
-			return found_child
-		}
-		let owner = parent.as_ref().unwrap();
-		match Self::decode_nft_account_id::<T::AccountId>(owner.clone()) {
-			None => found_child,
-			Some((cid, nid)) => {
-				if (cid, nid) == (parent_collection_id, parent_nft_id) {
-					found_child = true
-				} else {
-					found_child = Pallet::<T>::is_x_descendent_of_y(
-						cid,
-						nid,
-						parent_collection_id,
-						parent_nft_id,
-					)
-				}
-				found_child
-			},
+	) -> Result<bool, Error<T>> {
+		let mut current = (child_collection_id, child_nft_id);
+		for _ in 0..=T::MaxRecursions::get() {
+			// An owner of None indicates the NFT is not available
+			let owner = match pallet_uniques::Pallet::<T>::owner(current.0, current.1) {
+				Some(owner) => owner,
+				None => return Ok(false),
+			};
+			match Self::decode_nft_account_id::<T::AccountId>(owner) {
+				None => return Ok(false),
+				Some(parent) if parent == (parent_collection_id, parent_nft_id) => return Ok(true),
+				Some(parent) => current = parent,
+			}
 		}
+		Err(Error::<T>::TooManyRecursions)
 	}
 
 	pub fn get_next_nft_id(collection_id: CollectionId) -> Result<NftId, Error<T>> {
@@ -796,6 +2196,314 @@ where
 		lock_status
 	}
 
+	/// Ensures an NFT can be rejected by `sender`: it must exist, be pending and be root-owned
+	/// by `sender`.  Returns the NFT info.
+	fn ensure_rejectable(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+	) -> Result<InstanceInfoOf<T>, DispatchError> {
+		// Look up root owner in Uniques to ensure permissions
+		let (root_owner, _root_nft) = Pallet::<T>::lookup_root_owner(collection_id, nft_id)?;
+
+		// Ensure NFT is pending (cannot reject non-pending NFT) and exists in Nfts storage
+		let nft = Nfts::<T>::get(collection_id, nft_id).ok_or(Error::<T>::NoAvailableNftId)?;
+		ensure!(nft.pending, Error::<T>::CannotRejectNonPendingNft);
+
+		// Check ownership
+		ensure!(sender == root_owner, Error::<T>::CannotRejectNonOwnedNft);
+
+		Ok(nft)
+	}
+
+	/// Applies the incoming policy of `recipient` to an NFT of `collection_id` that `sender`
+	/// sends or mints under one of its NFTs. Returns whether the NFT must still be accepted.
+	fn check_incoming_policy(
+		recipient: &T::AccountId,
+		sender: &T::AccountId,
+		collection_id: CollectionId,
+	) -> Result<bool, DispatchError> {
+		let policy = match IncomingPolicies::<T>::get(recipient) {
+			Some(policy) => policy,
+			None => return Ok(true),
+		};
+
+		ensure!(
+			!policy.blocked_collections.contains(&collection_id),
+			Error::<T>::IncomingNftBlocked
+		);
+		if policy.auto_accept_collections.contains(&collection_id) ||
+			policy.auto_accept_senders.contains(sender)
+		{
+			return Ok(false)
+		}
+		ensure!(!policy.refuse_all, Error::<T>::IncomingNftRefused);
+
+		Ok(true)
+	}
+
+	/// Whether a rejected or timed out NFT can be returned under its previous parent NFT: the
+	/// parent must still exist and be root-owned by the sender, must not descend from the NFT
+	/// and must have room for another child at the next depth and for the NFT's descendants,
+	/// and the sender must be able to pay the child deposit
+	fn can_return_under(
+		parent: (CollectionId, NftId),
+		nft: (CollectionId, NftId),
+		sender: &T::AccountId,
+	) -> bool {
+		// The deposit the sender already holds for the NFT is released before reserving anew
+		let held = match Self::child_deposits(nft) {
+			Some((depositor, deposit)) if depositor == *sender => deposit,
+			_ => Zero::zero(),
+		};
+		let deposit = T::ChildDeposit::get();
+		Nfts::<T>::contains_key(parent.0, parent.1) &&
+			(deposit <= held ||
+				<T as Config>::Currency::can_reserve(sender, deposit.saturating_sub(held))) &&
+			matches!(Self::is_x_descendent_of_y(parent.0, parent.1, nft.0, nft.1), Ok(false)) &&
+			Self::lookup_root_owner(parent.0, parent.1)
+				.map_or(false, |(root_owner, _)| root_owner == *sender) &&
+			Self::child_order(parent).len() < T::MaxChildrenPerNft::get() as usize &&
+			Self::nft_depth(parent) < T::MaxRecursions::get() &&
+			Self::can_add_descendants(parent, Self::descendant_count(nft).saturating_add(1))
+	}
+
+	/// Marks an NFT as pending since the current block, recording where it came from so that it
+	/// can be returned if rejected or timed out
+	///
+	/// Parameters:
+	/// - `collection_id`: Collection of the pending NFT
+	/// - `nft_id`: Pending NFT
+	/// - `sender`: Account that sent the NFT, i.e. its root owner at the time of sending
+	/// - `previous_owner`: Owner of the NFT before it was sent, either an account or an NFT
+	fn set_pending_nft(
+		collection_id: CollectionId,
+		nft_id: NftId,
+		sender: T::AccountId,
+		previous_owner: AccountIdOrCollectionNftTuple<T::AccountId>,
+	) {
+		// An NFT sent on while still pending restarts its timeout
+		Self::clear_pending_nft(collection_id, nft_id);
+		let since = <frame_system::Pallet<T>>::block_number();
+		PendingNfts::<T>::insert(
+			collection_id,
+			nft_id,
+			PendingNftInfo { sender, previous_owner, since },
+		);
+		PendingNftQueue::<T>::insert(since, (collection_id, nft_id), ());
+	}
+
+	/// Clears the pending record of an NFT, returning it if the NFT was pending
+	fn clear_pending_nft(
+		collection_id: CollectionId,
+		nft_id: NftId,
+	) -> Option<PendingNftInfoOf<T>> {
+		let pending_info = PendingNfts::<T>::take(collection_id, nft_id)?;
+		PendingNftQueue::<T>::remove(pending_info.since, (collection_id, nft_id));
+		Some(pending_info)
+	}
+
+	/// Returns the NFTs pending for longer than `PendingTimeout` to their senders, oldest first,
+	/// within `remaining_weight`
+	///
+	/// Parameters:
+	/// - `now`: Current block number
+	/// - `remaining_weight`: Weight available for the sweep
+	///
+	/// Output:
+	/// - The weight consumed by the sweep
+	pub fn expire_pending_nfts(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		let block_weight = db_weight.reads(1);
+		let nft_weight = (10_000 + db_weight.reads_writes(8, 8))
+			.saturating_add(Self::traversal_weight(2))
+			.saturating_add(Self::subtree_weight());
+		let mut consumed = db_weight.reads_writes(1, 1);
+		if consumed > remaining_weight {
+			return 0
+		}
+
+		let deadline = match now.checked_sub(&T::PendingTimeout::get()) {
+			Some(deadline) => deadline,
+			None => return db_weight.reads(1),
+		};
+		let mut cursor = Self::pending_sweep_cursor();
+		while cursor <= deadline && consumed.saturating_add(block_weight) <= remaining_weight {
+			consumed = consumed.saturating_add(block_weight);
+			let budget = (remaining_weight.saturating_sub(consumed) / nft_weight) as usize;
+			// Fetch one more than the budget to learn whether the block is swept entirely
+			let timed_out: Vec<(CollectionId, NftId)> =
+				PendingNftQueue::<T>::iter_key_prefix(cursor).take(budget + 1).collect();
+			let block_swept = timed_out.len() <= budget;
+
+			for (collection_id, nft_id) in timed_out.into_iter().take(budget) {
+				consumed = consumed.saturating_add(nft_weight);
+				// The NFT leaves the queue even if it cannot be returned, so the sweep moves on
+				PendingNftQueue::<T>::remove(cursor, (collection_id, nft_id));
+				if let Ok(returned_to) = Self::return_timed_out_nft(collection_id, nft_id) {
+					Self::deposit_event(Event::PendingNftTimedOut {
+						collection_id,
+						nft_id,
+						returned_to,
+					});
+				}
+			}
+
+			if !block_swept {
+				break
+			}
+			cursor = cursor.saturating_add(One::one());
+		}
+		PendingSweepCursor::<T>::put(cursor);
+
+		consumed
+	}
+
+	/// Returns a timed out pending NFT to its sender, as if its root owner rejected it
+	#[transactional]
+	fn return_timed_out_nft(
+		collection_id: CollectionId,
+		nft_id: NftId,
+	) -> Result<AccountIdOrCollectionNftTuple<T::AccountId>, DispatchError> {
+		let (root_owner, _) = Self::lookup_root_owner(collection_id, nft_id)?;
+		let (returned_to_account, returned_to) =
+			Self::nft_reject(root_owner, collection_id, nft_id)?;
+		pallet_uniques::Pallet::<T>::do_transfer(
+			collection_id,
+			nft_id,
+			returned_to_account,
+			|_class_details, _details| Ok(()),
+		)?;
+		Ok(returned_to)
+	}
+
+	/// Burns an NFT without its descendants, which are detached and queued for deletion in
+	/// `on_idle` instead. The NFT itself is removed right away, leaving its descendants
+	/// unreachable until they are deleted.
+	///
+	/// Parameters:
+	/// - `root_owner`: Root owner of the NFT, credited with the burn of every queued descendant
+	/// - `collection_id`: Collection of the NFT
+	/// - `nft_id`: NFT to burn
+	#[transactional]
+	pub fn nft_burn_lazily(
+		root_owner: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+	) -> DispatchResult {
+		Self::remove_nft_storage(collection_id, nft_id, Some(&root_owner))?;
+		for (child, _) in Children::<T>::drain_prefix((collection_id, nft_id)) {
+			Self::queue_lazy_burn(&root_owner, child);
+		}
+		pallet_uniques::Pallet::<T>::do_burn(collection_id, nft_id, |_, _| Ok(()))
+	}
+
+	/// Queues an NFT detached from a lazily burned parent for deletion. Being unreachable, it
+	/// leaves the inventory and pending inbox of its root owner right away.
+	///
+	/// Parameters:
+	/// - `root_owner`: Root owner of the burned tree, credited with the burn of the NFT
+	/// - `nft`: Tuple of (CollectionId, NftId) of the NFT to queue
+	pub fn queue_lazy_burn(root_owner: &T::AccountId, nft: (CollectionId, NftId)) {
+		NftsByRootOwner::<T>::remove(root_owner, nft);
+		for item in Self::pending_items(nft.0, nft.1) {
+			PendingInbox::<T>::remove(root_owner, item);
+		}
+		LazyBurns::<T>::insert(nft, root_owner.clone());
+		LazyBurnQueueBounds::<T>::mutate(|(_, next)| {
+			LazyBurnQueue::<T>::insert(*next, nft);
+			*next = next.saturating_add(1);
+		});
+	}
+
+	/// Deletes NFTs queued by `nft_burn_lazily` in the order they were queued within
+	/// `remaining_weight`. The resources and properties of the NFT at the head of the queue are
+	/// removed a few at a time, resuming in a later block when the weight runs out, then the NFT
+	/// itself is deleted, queueing its own children in turn.
+	///
+	/// Parameters:
+	/// - `remaining_weight`: Weight available for the deletion
+	///
+	/// Output:
+	/// - The weight consumed by the deletion
+	pub fn process_lazy_burns(remaining_weight: Weight) -> Weight {
+		let item_weight = Self::item_removal_weight(1);
+		let nft_weight = Self::lazy_burn_weight();
+		let mut consumed = T::DbWeight::get().reads(1);
+		if consumed > remaining_weight {
+			return 0
+		}
+
+		loop {
+			let (first, next) = Self::lazy_burn_queue_bounds();
+			if first == next {
+				break
+			}
+
+			let queued = LazyBurnQueue::<T>::get(first);
+			if let Some((collection_id, nft_id)) =
+				queued.filter(|nft| LazyBurns::<T>::contains_key(nft))
+			{
+				// Remove the resources and properties of the NFT first, as many as the weight
+				// left allows
+				let items = remaining_weight.saturating_sub(consumed) / item_weight;
+				let limit = items.min(u32::MAX as u64) as u32;
+				let removed = Self::remove_nft_items(collection_id, nft_id, limit);
+				consumed = consumed.saturating_add(item_weight.saturating_mul(removed as u64));
+				if removed == limit && Self::has_nft_items(collection_id, nft_id) {
+					break
+				}
+			}
+			if consumed.saturating_add(nft_weight) > remaining_weight {
+				break
+			}
+			consumed = consumed.saturating_add(nft_weight);
+
+			// The NFT leaves the queue even if it cannot be deleted, so the deletion moves on
+			LazyBurnQueueBounds::<T>::put((first.saturating_add(1), next));
+			LazyBurnQueue::<T>::remove(first);
+			let (collection_id, nft_id) = match queued {
+				Some(nft) => nft,
+				None => continue,
+			};
+			if let Some(owner) = LazyBurns::<T>::take((collection_id, nft_id)) {
+				if Self::nft_burn_lazily(owner.clone(), collection_id, nft_id).is_ok() {
+					Self::deposit_event(Event::NFTBurned { owner, nft_id });
+				}
+			}
+		}
+
+		consumed
+	}
+
+	/// Accepts a pending NFT where it already is, under its current parent NFT or account
+	///
+	/// Parameters:
+	/// - `sender`: Root owner accepting the NFT
+	/// - `collection_id`: Collection of the pending NFT
+	/// - `nft_id`: Pending NFT to accept
+	///
+	/// Output:
+	/// - The account or NFT the NFT was accepted into
+	pub fn accept_in_place(
+		sender: T::AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+	) -> Result<AccountIdOrCollectionNftTuple<T::AccountId>, DispatchError> {
+		let nft = Nfts::<T>::get(collection_id, nft_id).ok_or(Error::<T>::NoAvailableNftId)?;
+		ensure!(nft.pending, Error::<T>::CannotAcceptNonPendingNft);
+
+		let owner = pallet_uniques::Pallet::<T>::owner(collection_id, nft_id)
+			.ok_or(Error::<T>::NoAvailableNftId)?;
+		let new_owner = match Self::decode_nft_account_id::<T::AccountId>(owner.clone()) {
+			Some((cid, nid)) => AccountIdOrCollectionNftTuple::CollectionAndNftTuple(cid, nid),
+			None => AccountIdOrCollectionNftTuple::AccountId(owner),
+		};
+		Self::nft_accept(sender, collection_id, nft_id, new_owner.clone())?;
+
+		Ok(new_owner)
+	}
+
 	// Check NFT is transferable
 	pub fn check_is_transferable(nft: &InstanceInfoOf<T>) -> DispatchResult {
 		ensure!(nft.transferable, Error::<T>::NonTransferable);
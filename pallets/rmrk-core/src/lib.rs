@@ -7,7 +7,10 @@
 #![allow(clippy::too_many_arguments)]
 
 use frame_support::{
-	dispatch::DispatchResult, ensure, traits::tokens::nonfungibles::*, transactional, BoundedVec,
+	dispatch::DispatchResult,
+	ensure,
+	traits::{tokens::nonfungibles::*, Currency, ReservableCurrency},
+	transactional, BoundedVec,
 };
 use frame_system::ensure_signed;
 
@@ -16,13 +19,17 @@ use sp_std::convert::TryInto;
 
 use rmrk_traits::{
 	primitives::*, AccountIdOrCollectionNftTuple, BasicResource, Collection, CollectionInfo,
-	ComposableResource, Nft, NftInfo, Priority, Property, Resource, ResourceInfo, ResourceTypes,
-	RoyaltyInfo, SlotResource,
+	ComposableResource, EffectiveResource, InspectBase, Nft, NftInfo, OnNftBurned,
+	OnResourceRemoved, PendingItem, Priority, Property, PropertyPermission, PropertyType, Resource,
+	ResourceInfo, ResourceTypes, RoyaltyInfo, SlotResource,
 };
-use sp_std::result::Result;
+use sp_std::{result::Result, vec::Vec};
+use types::{IncomingPolicy, MintBatchEntry, PendingNftInfo, ResourceTargets};
 
 mod functions;
 
+pub mod migration;
+
 #[cfg(test)]
 mod mock;
 
@@ -32,6 +39,13 @@ mod tests;
 pub type InstanceInfoOf<T> = NftInfo<
 	<T as frame_system::Config>::AccountId,
 	BoundedVec<u8, <T as pallet_uniques::Config>::StringLimit>,
+	BoundedRoyaltySharesOf<T>,
+>;
+pub type CollectionInfoOf<T> = CollectionInfo<
+	StringLimitOf<T>,
+	BoundedCollectionSymbolOf<T>,
+	<T as frame_system::Config>::AccountId,
+	BoundedRoyaltySharesOf<T>,
 >;
 pub type ResourceOf<T, P> =
 	ResourceInfo<BoundedVec<u8, <T as pallet_uniques::Config>::StringLimit>, BoundedVec<PartId, P>>;
@@ -46,6 +60,21 @@ pub type KeyLimitOf<T> = BoundedVec<u8, <T as pallet_uniques::Config>::KeyLimit>
 
 pub type ValueLimitOf<T> = BoundedVec<u8, <T as pallet_uniques::Config>::ValueLimit>;
 
+pub type BoundedRoyaltySharesOf<T> = BoundedVec<
+	(<T as frame_system::Config>::AccountId, Permill),
+	<T as Config>::MaxRoyaltyRecipients,
+>;
+
+pub type RoyaltyInfoOf<T> = RoyaltyInfo<BoundedRoyaltySharesOf<T>>;
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type ResourceTypeOf<T> = ResourceTypes<
+	BoundedVec<u8, <T as pallet_uniques::Config>::StringLimit>,
+	BoundedVec<PartId, <T as Config>::PartsLimit>,
+>;
+
 pub type BoundedResourceTypeOf<T> = BoundedVec<
 	ResourceTypes<
 		BoundedVec<u8, <T as pallet_uniques::Config>::StringLimit>,
@@ -54,6 +83,25 @@ pub type BoundedResourceTypeOf<T> = BoundedVec<
 	<T as Config>::MaxResourcesOnMint,
 >;
 
+pub type MintBatchEntryOf<T> = MintBatchEntry<
+	<T as frame_system::Config>::AccountId,
+	RoyaltyInfoOf<T>,
+	StringLimitOf<T>,
+	BoundedResourceTypeOf<T>,
+>;
+
+pub type PendingNftInfoOf<T> = PendingNftInfo<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
+pub type ResourceTargetsOf<T> = ResourceTargets<BoundedVec<NftId, <T as Config>::MaxBulkResources>>;
+
+pub type IncomingPolicyOf<T> = IncomingPolicy<
+	BoundedVec<CollectionId, <T as Config>::MaxPolicyEntries>,
+	BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxPolicyEntries>,
+>;
+
 pub mod types;
 
 // Re-export pallet items so that they can be accessed from the crate namespace.
@@ -88,6 +136,77 @@ pub mod pallet {
 		type CollectionSymbolLimit: Get<u32>;
 
 		type MaxResourcesOnMint: Get<u32>;
+
+		/// The maximum number of NFTs that can be minted in a single `mint_nft_batch` call
+		#[pallet::constant]
+		type MaxBatchMint: Get<u32>;
+
+		/// Number of blocks after which a pending NFT is returned to its sender
+		#[pallet::constant]
+		type PendingTimeout: Get<Self::BlockNumber>;
+
+		/// The maximum number of NFTs that can be accepted in a single `accept_nfts` or
+		/// `accept_all_pending` call
+		#[pallet::constant]
+		type MaxBatchAccept: Get<u32>;
+
+		/// The maximum number of NFTs a single `add_resource_to_nfts` call can target
+		#[pallet::constant]
+		type MaxBulkResources: Get<u32>;
+
+		/// The maximum number of children, pending or not, an NFT can have
+		#[pallet::constant]
+		type MaxChildrenPerNft: Get<u32>;
+
+		/// The maximum number of descendants, nested at any depth, an NFT can have
+		#[pallet::constant]
+		type MaxDescendants: Get<u32>;
+
+		/// The maximum number of resources an NFT can have, and a collection can share with
+		/// all of its NFTs
+		#[pallet::constant]
+		type MaxResourcesPerNft: Get<u32>;
+
+		/// The maximum number of properties an NFT can have, counted apart for those set by the
+		/// issuer and by the owner
+		#[pallet::constant]
+		type MaxPropertiesPerNft: Get<u32>;
+
+		/// The maximum number of collections or senders in each list of an incoming policy
+		#[pallet::constant]
+		type MaxPolicyEntries: Get<u32>;
+
+		/// The maximum number of recipients sharing the royalty of an NFT or collection
+		#[pallet::constant]
+		type MaxRoyaltyRecipients: Get<u32>;
+
+		/// Handler notified when a resource is removed from an NFT, e.g. to unequip items
+		type OnResourceRemoved: OnResourceRemoved;
+
+		/// Handler notified when an NFT is burned, e.g. to unequip the items it equips
+		type OnNftBurned: OnNftBurned;
+
+		/// Bases that composable and slot resources are validated against
+		type Bases: InspectBase;
+
+		/// The maximum number of properties that can be set in a single `set_properties` call
+		#[pallet::constant]
+		type MaxPropertiesBatch: Get<u32>;
+
+		/// The currency mechanism used to reserve storage deposits.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The deposit reserved from the collection issuer for each resource added to an NFT
+		#[pallet::constant]
+		type ResourceDeposit: Get<BalanceOf<Self>>;
+
+		/// The deposit reserved from the collection issuer per byte of property key and value
+		#[pallet::constant]
+		type PropertyDepositPerByte: Get<BalanceOf<Self>>;
+
+		/// The deposit reserved from the sender for each NFT that becomes a child of another NFT
+		#[pallet::constant]
+		type ChildDeposit: Get<BalanceOf<Self>>;
 	}
 
 	#[pallet::storage]
@@ -114,12 +233,8 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn collections)]
 	/// Stores collections info
-	pub type Collections<T: Config> = StorageMap<
-		_,
-		Twox64Concat,
-		CollectionId,
-		CollectionInfo<StringLimitOf<T>, BoundedCollectionSymbolOf<T>, T::AccountId>,
-	>;
+	pub type Collections<T: Config> =
+		StorageMap<_, Twox64Concat, CollectionId, CollectionInfoOf<T>>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn nfts)]
@@ -127,6 +242,79 @@ pub mod pallet {
 	pub type Nfts<T: Config> =
 		StorageDoubleMap<_, Twox64Concat, CollectionId, Twox64Concat, NftId, InstanceInfoOf<T>>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn nfts_by_root_owner)]
+	/// Stores the NFTs root-owned by an account, either directly or nested in NFTs it owns
+	pub type NftsByRootOwner<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		(CollectionId, NftId),
+		(),
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_inbox)]
+	/// Stores the pending NFTs and resource changes awaiting the decision of an account, as the
+	/// root owner of the NFTs they belong to
+	pub type PendingInbox<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, PendingItem, ()>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_nfts)]
+	/// Stores the origin of pending nfts, used to return them when rejected
+	pub type PendingNfts<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CollectionId, Twox64Concat, NftId, PendingNftInfoOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_nft_queue)]
+	/// Stores pending nfts by the block they became pending at, swept once timed out
+	pub type PendingNftQueue<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, (CollectionId, NftId), ()>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_sweep_cursor)]
+	/// Earliest block whose pending nfts may not have been swept yet
+	pub type PendingSweepCursor<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn lazy_burns)]
+	/// Stores the descendants of lazily burned NFTs awaiting deletion, along with the account
+	/// that burned them
+	pub type LazyBurns<T: Config> =
+		StorageMap<_, Twox64Concat, (CollectionId, NftId), T::AccountId>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn lazy_burn_queue)]
+	/// Stores the NFTs of `LazyBurns` by the order they were queued in, so they are deleted
+	/// first in, first out
+	pub type LazyBurnQueue<T: Config> = StorageMap<_, Twox64Concat, u64, (CollectionId, NftId)>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn lazy_burn_queue_bounds)]
+	/// Index of the first NFT in `LazyBurnQueue`, and index the next queued NFT will get
+	pub type LazyBurnQueueBounds<T: Config> = StorageValue<_, (u64, u64), ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn incoming_policies)]
+	/// Stores the policy each account applies to NFTs sent or minted under its NFTs
+	pub type IncomingPolicies<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, IncomingPolicyOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn approvals)]
+	/// Stores the operator approved to send or burn an NFT on behalf of its root owner, along
+	/// with the block the approval expires at, if any
+	pub type Approvals<T: Config> =
+		StorageMap<_, Twox64Concat, (CollectionId, NftId), (T::AccountId, Option<T::BlockNumber>)>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn operator_approvals)]
+	/// Stores the operators approved to send or burn every NFT root-owned by an account
+	pub type OperatorApprovals<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, ()>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn priorities)]
 	/// Stores priority info
@@ -153,6 +341,29 @@ pub mod pallet {
 		(),
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn nft_depth)]
+	/// Stores the number of NFTs above each nested NFT, 0 (not stored) if owned by an account
+	pub type NftDepth<T: Config> =
+		StorageMap<_, Twox64Concat, (CollectionId, NftId), u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn descendant_count)]
+	/// Stores the number of NFTs nested at any depth under each NFT, 0 (not stored) if none
+	pub type DescendantCount<T: Config> =
+		StorageMap<_, Twox64Concat, (CollectionId, NftId), u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn child_order)]
+	/// Stores the children of each NFT in the order chosen by its root owner
+	pub type ChildOrder<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		(CollectionId, NftId),
+		BoundedVec<(CollectionId, NftId), T::MaxChildrenPerNft>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn resources)]
 	/// Stores resource info
@@ -167,9 +378,55 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn pending_resource_replacements)]
+	/// Stores replacements of resources awaiting acceptance by the root owner of the NFT
+	pub type PendingResourceReplacements<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, CollectionId>,
+			NMapKey<Blake2_128Concat, NftId>,
+			NMapKey<Blake2_128Concat, ResourceId>,
+		),
+		ResourceTypeOf<T>,
+		OptionQuery,
+	>;
+
+	/// Next available shared resource ID of a collection.
+	#[pallet::storage]
+	#[pallet::getter(fn next_collection_resource_id)]
+	pub type NextCollectionResourceId<T: Config> =
+		StorageMap<_, Twox64Concat, CollectionId, ResourceId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_resources)]
+	/// Stores resources shared by every NFT of a collection, stored once instead of per NFT
+	pub type CollectionResources<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CollectionId,
+		Twox64Concat,
+		ResourceId,
+		ResourceTypeOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_resource_opt_outs)]
+	/// Stores the NFTs that opted out of a shared resource of their collection
+	pub type CollectionResourceOptOuts<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, CollectionId>,
+			NMapKey<Blake2_128Concat, ResourceId>,
+			NMapKey<Blake2_128Concat, NftId>,
+		),
+		(),
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn equippable_bases)]
-	/// Stores the existence of a base for a particular NFT
+	/// Stores the number of composable resources of a particular NFT using a base
 	/// This is populated on `add_composable_resource`, and is
 	/// used in the rmrk-equip pallet when equipping a resource.
 	pub type EquippableBases<T: Config> = StorageNMap<
@@ -179,7 +436,7 @@ pub mod pallet {
 			NMapKey<Blake2_128Concat, NftId>,
 			NMapKey<Blake2_128Concat, BaseId>,
 		),
-		(),
+		u32,
 	>;
 
 	#[pallet::storage]
@@ -214,15 +471,147 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn property_deposits)]
+	/// Stores the account and amount of the deposit reserved for each property written by an
+	/// account other than the collection issuer, whose deposits are held for the collection
+	pub type PropertyDeposits<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, CollectionId>,
+			NMapKey<Blake2_128Concat, Option<NftId>>,
+			NMapKey<Blake2_128Concat, KeyLimitOf<T>>,
+		),
+		(T::AccountId, BalanceOf<T>),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn owner_properties)]
+	/// Properties written by the root owner of an NFT, separate from the issuer's properties
+	pub type OwnerProperties<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, CollectionId>,
+			NMapKey<Blake2_128Concat, NftId>,
+			NMapKey<Blake2_128Concat, KeyLimitOf<T>>,
+		),
+		ValueLimitOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn owner_property_deposits)]
+	/// Stores the account and amount of the deposit reserved for each owner property
+	pub type OwnerPropertyDeposits<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, CollectionId>,
+			NMapKey<Blake2_128Concat, NftId>,
+			NMapKey<Blake2_128Concat, KeyLimitOf<T>>,
+		),
+		(T::AccountId, BalanceOf<T>),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn property_permissions)]
+	/// Who may write the properties of a key in a collection
+	pub type PropertyPermissions<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CollectionId,
+		Blake2_128Concat,
+		KeyLimitOf<T>,
+		PropertyPermission,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn property_schemas)]
+	/// Declared type of the values of a property key in a collection
+	pub type PropertySchemas<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CollectionId,
+		Blake2_128Concat,
+		KeyLimitOf<T>,
+		PropertyType,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_deposits)]
+	/// Stores the total resource and property deposit reserved from a collection's issuer
+	pub type CollectionDeposits<T: Config> =
+		StorageMap<_, Twox64Concat, CollectionId, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn child_deposits)]
+	/// Stores the account and amount of the deposit reserved for an NFT being a child
+	pub type ChildDeposits<T: Config> =
+		StorageMap<_, Twox64Concat, (CollectionId, NftId), (T::AccountId, BalanceOf<T>)>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn nft_metadata_frozen)]
+	/// Whether the metadata of an NFT is frozen and can no longer be changed
+	pub type NftMetadataFrozen<T: Config> =
+		StorageMap<_, Twox64Concat, (CollectionId, NftId), bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn collection_metadata_frozen)]
+	/// Whether the metadata of a collection is frozen and can no longer be changed
+	pub type CollectionMetadataFrozen<T: Config> =
+		StorageMap<_, Twox64Concat, CollectionId, bool, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn lock)]
 	/// Lock for NFTs
 	pub type Lock<T: Config> = StorageMap<_, Twox64Concat, (CollectionId, NftId), bool, ValueQuery>;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(10);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T>
+	where
+		T: pallet_uniques::Config<CollectionId = CollectionId, ItemId = NftId>,
+	{
+		fn on_runtime_upgrade() -> Weight {
+			migration::v1::migrate::<T>()
+				.saturating_add(migration::v2::migrate::<T>())
+				.saturating_add(migration::v3::migrate::<T>())
+				.saturating_add(migration::v4::migrate::<T>())
+				.saturating_add(migration::v5::migrate::<T>())
+				.saturating_add(migration::v6::migrate::<T>())
+				.saturating_add(migration::v7::migrate::<T>())
+				.saturating_add(migration::v8::migrate::<T>())
+				.saturating_add(migration::v9::migrate::<T>())
+				.saturating_add(migration::v10::migrate::<T>())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			migration::try_runtime::pre_upgrade::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			migration::try_runtime::post_upgrade::<T>()
+		}
+
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let consumed = Self::expire_pending_nfts(now, remaining_weight);
+			consumed
+				.saturating_add(Self::process_lazy_burns(remaining_weight.saturating_sub(consumed)))
+		}
+	}
+
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/v3/runtime/events-and-errors
 	#[pallet::event]
@@ -238,6 +627,13 @@ pub mod pallet {
 			collection_id: CollectionId,
 			nft_id: NftId,
 		},
+		/// A contiguous range of NFTs was minted by `mint_nft_batch`
+		NftBatchMinted {
+			issuer: T::AccountId,
+			collection_id: CollectionId,
+			first_nft_id: NftId,
+			last_nft_id: NftId,
+		},
 		NFTBurned {
 			owner: T::AccountId,
 			nft_id: NftId,
@@ -259,10 +655,47 @@ pub mod pallet {
 			collection_id: CollectionId,
 			nft_id: NftId,
 		},
+		PendingNftTimedOut {
+			collection_id: CollectionId,
+			nft_id: NftId,
+			returned_to: AccountIdOrCollectionNftTuple<T::AccountId>,
+		},
+		/// The root owner of an NFT changed the order of its children
+		ChildrenReordered {
+			collection_id: CollectionId,
+			nft_id: NftId,
+		},
+		/// An account set or cleared (`None`) the policy for NFTs sent to its NFTs
+		IncomingPolicySet {
+			account: T::AccountId,
+			policy: Option<IncomingPolicyOf<T>>,
+		},
+		/// The root owner of an NFT approved an operator to send or burn it
+		Approved {
+			owner: T::AccountId,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			operator: T::AccountId,
+			expiry: Option<T::BlockNumber>,
+		},
+		/// The root owner of an NFT cancelled the approval of its operator
+		ApprovalCancelled {
+			owner: T::AccountId,
+			collection_id: CollectionId,
+			nft_id: NftId,
+		},
+		/// An account approved (or revoked) an operator for all the NFTs it root-owns
+		ApprovalForAll {
+			owner: T::AccountId,
+			operator: T::AccountId,
+			approved: bool,
+		},
 		NFTRejected {
 			sender: T::AccountId,
 			collection_id: CollectionId,
 			nft_id: NftId,
+			/// Where the NFT was returned to, `None` if it was burned instead
+			returned_to: Option<AccountIdOrCollectionNftTuple<T::AccountId>>,
 		},
 		IssuerChanged {
 			old_issuer: T::AccountId,
@@ -275,10 +708,51 @@ pub mod pallet {
 			key: KeyLimitOf<T>,
 			value: ValueLimitOf<T>,
 		},
+		PropertyRemoved {
+			collection_id: CollectionId,
+			maybe_nft_id: Option<NftId>,
+			key: KeyLimitOf<T>,
+		},
+		PropertySchemaSet {
+			collection_id: CollectionId,
+			key: KeyLimitOf<T>,
+			property_type: Option<PropertyType>,
+		},
+		OwnerPropertySet {
+			collection_id: CollectionId,
+			nft_id: NftId,
+			key: KeyLimitOf<T>,
+			value: ValueLimitOf<T>,
+		},
+		OwnerPropertyRemoved {
+			collection_id: CollectionId,
+			nft_id: NftId,
+			key: KeyLimitOf<T>,
+		},
+		PropertyPermissionSet {
+			collection_id: CollectionId,
+			key: KeyLimitOf<T>,
+			permission: PropertyPermission,
+		},
 		CollectionLocked {
 			issuer: T::AccountId,
 			collection_id: CollectionId,
 		},
+		CollectionRoyaltySet {
+			collection_id: CollectionId,
+			royalty: Option<RoyaltyInfoOf<T>>,
+		},
+		NftMetadataSet {
+			collection_id: CollectionId,
+			nft_id: NftId,
+			metadata: StringLimitOf<T>,
+			frozen: bool,
+		},
+		CollectionMetadataSet {
+			collection_id: CollectionId,
+			metadata: StringLimitOf<T>,
+			frozen: bool,
+		},
 		ResourceAdded {
 			nft_id: NftId,
 			resource_id: ResourceId,
@@ -295,11 +769,37 @@ pub mod pallet {
 			nft_id: NftId,
 			resource_id: ResourceId,
 		},
-		PrioritySet {
+		ResourceRejected {
+			nft_id: NftId,
+			resource_id: ResourceId,
+		},
+		ResourceReplaced {
+			nft_id: NftId,
+			resource_id: ResourceId,
+		},
+		ResourceReplacementAccepted {
+			nft_id: NftId,
+			resource_id: ResourceId,
+		},
+		CollectionResourceAdded {
+			collection_id: CollectionId,
+			resource_id: ResourceId,
+		},
+		CollectionResourceRemoved {
+			collection_id: CollectionId,
+			resource_id: ResourceId,
+		},
+		CollectionResourceOptOutSet {
 			collection_id: CollectionId,
 			nft_id: NftId,
+			resource_id: ResourceId,
+			opt_out: bool,
 		},
-	}
+		PrioritySet {
+			collection_id: CollectionId,
+			nft_id: NftId,
+		},
+	}
 
 	// Errors inform users that something went wrong.
 	#[pallet::error]
@@ -329,6 +829,26 @@ pub mod pallet {
 		CannotAcceptNonOwnedNft,
 		CannotRejectNonOwnedNft,
 		CannotRejectNonPendingNft,
+		/// Accepting an NFT that is not pending should fail
+		CannotAcceptNonPendingNft,
+		/// Pending NFT has no recorded origin to be returned to
+		PendingNftOriginUnknown,
+		/// The parent NFT already has `MaxChildrenPerNft` children
+		TooManyChildren,
+		/// The top NFT of the tree would end up with more than `MaxDescendants` descendants
+		TooManyDescendants,
+		/// The NFT or collection already has `MaxResourcesPerNft` resources
+		TooManyResources,
+		/// The NFT already has `MaxPropertiesPerNft` properties
+		TooManyProperties,
+		/// A new child order must list every child of the NFT exactly once
+		InvalidChildOrder,
+		/// The recipient blocks NFTs of this collection
+		IncomingNftBlocked,
+		/// The recipient refuses NFTs it doesn't auto-accept
+		IncomingNftRefused,
+		/// The NFT has no approved operator
+		ApprovalNotFound,
 		ResourceDoesntExist,
 		/// Accepting a resource that is not pending should fail
 		ResourceNotPending,
@@ -336,6 +856,26 @@ pub mod pallet {
 		// Must unequip an item before sending (this only applies to the
 		// rmrk-equip pallet but the send operation lives in rmrk-core)
 		CannotSendEquippedItem,
+		/// A batch mint must contain at least one NFT
+		EmptyMintBatch,
+		/// The royalty shares add up to more than 100%
+		RoyaltyExceedsTotal,
+		/// Metadata was frozen and can no longer be changed
+		MetadataFrozen,
+		/// Removing a property that is not set
+		PropertyDoesntExist,
+		/// The property key is immutable and its value can no longer be changed
+		PropertyImmutable,
+		/// The property value does not match the type declared in the collection schema
+		PropertyTypeMismatch,
+		/// The base referenced by a resource doesn't exist
+		BaseDoesntExist,
+		/// A part referenced by a resource doesn't belong to its base
+		PartDoesntExist,
+		/// The slot referenced by a resource is not a slot part of its base
+		NotASlotPart,
+		/// Bulk resource targets are empty, inverted or exceed `MaxBulkResources`
+		InvalidResourceTargets,
 	}
 
 	#[pallet::call]
@@ -349,8 +889,8 @@ pub mod pallet {
 		/// Parameters:
 		/// - `collection_id`: The class of the asset to be minted.
 		/// - `nft_id`: The nft value of the asset to be minted.
-		/// - `recipient`: Receiver of the royalty
-		/// - `royalty`: Permillage reward from each trade for the Recipient
+		/// - `royalty`: Recipients of the royalty and their permillage reward from each trade (a
+		///   royalty without recipients is paid entirely to the minter)
 		/// - `metadata`: Arbitrary data about an nft, e.g. IPFS hash
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
 		#[transactional]
@@ -358,8 +898,7 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			owner: Option<T::AccountId>,
 			collection_id: CollectionId,
-			royalty_recipient: Option<T::AccountId>,
-			royalty: Option<Permill>,
+			royalty: Option<RoyaltyInfoOf<T>>,
 			metadata: BoundedVec<u8, T::StringLimit>,
 			transferable: bool,
 			resources: Option<BoundedResourceTypeOf<T>>,
@@ -384,7 +923,6 @@ pub mod pallet {
 				sender.clone(),
 				nft_owner.clone(),
 				collection_id,
-				royalty_recipient,
 				royalty,
 				metadata,
 				transferable,
@@ -413,23 +951,95 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Mints a batch of NFTs in the specified collection
+		///
+		/// NFTs receive consecutive ids, so a single `NftBatchMinted` event covering the minted
+		/// range is emitted instead of one `NftMinted` event per NFT.
+		///
+		/// Parameters:
+		/// - `collection_id`: The class of the assets to be minted.
+		/// - `nfts`: Owner, royalty, metadata, transferability and resources of each NFT
+		#[pallet::weight(
+			(10_000 + T::DbWeight::get().reads_writes(1, 1)).saturating_mul(nfts.len() as u64)
+		)]
+		#[transactional]
+		pub fn mint_nft_batch(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nfts: BoundedVec<MintBatchEntryOf<T>, T::MaxBatchMint>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			if let Some(collection_issuer) =
+				pallet_uniques::Pallet::<T>::collection_owner(collection_id)
+			{
+				ensure!(collection_issuer == sender, Error::<T>::NoPermission);
+			} else {
+				return Err(Error::<T>::CollectionUnknown.into())
+			}
+			ensure!(!nfts.is_empty(), Error::<T>::EmptyMintBatch);
+
+			let mut minted: Option<(NftId, NftId)> = None;
+			for entry in nfts {
+				// Extract intended owner or default to sender
+				let nft_owner = entry.owner.unwrap_or_else(|| sender.clone());
+
+				// Mint NFT for RMRK storage, failing once the collection max is reached
+				let (collection_id, nft_id) = Self::nft_mint(
+					sender.clone(),
+					nft_owner.clone(),
+					collection_id,
+					entry.royalty,
+					entry.metadata,
+					entry.transferable,
+				)?;
+
+				pallet_uniques::Pallet::<T>::do_mint(
+					collection_id,
+					nft_id,
+					nft_owner,
+					|_details| Ok(()),
+				)?;
+
+				// Add all at-mint resources
+				if let Some(resources) = entry.resources {
+					for res in resources {
+						Self::resource_add(sender.clone(), collection_id, nft_id, res, true)?;
+					}
+				}
+
+				minted = Some((minted.map_or(nft_id, |(first, _)| first), nft_id));
+			}
+
+			if let Some((first_nft_id, last_nft_id)) = minted {
+				Self::deposit_event(Event::NftBatchMinted {
+					issuer: sender,
+					collection_id,
+					first_nft_id,
+					last_nft_id,
+				});
+			}
+
+			Ok(())
+		}
+
 		/// Mints an NFT in the specified collection directly to another NFT
 		/// Sets metadata and the royalty attribute
 		///
 		/// Parameters:
 		/// - `collection_id`: The class of the asset to be minted.
 		/// - `nft_id`: The nft value of the asset to be minted.
-		/// - `recipient`: Receiver of the royalty
-		/// - `royalty`: Permillage reward from each trade for the Recipient
+		/// - `royalty`: Recipients of the royalty and their permillage reward from each trade (a
+		///   royalty without recipients is paid entirely to the minter)
 		/// - `metadata`: Arbitrary data about an nft, e.g. IPFS hash
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads_writes(1, 1) + Pallet::<T>::traversal_weight(1)
+		)]
 		#[transactional]
 		pub fn mint_nft_directly_to_nft(
 			origin: OriginFor<T>,
 			owner: (CollectionId, NftId),
 			collection_id: CollectionId,
-			royalty_recipient: Option<T::AccountId>,
-			royalty: Option<Permill>,
+			royalty: Option<RoyaltyInfoOf<T>>,
 			metadata: BoundedVec<u8, T::StringLimit>,
 			transferable: bool,
 			resources: Option<BoundedResourceTypeOf<T>>,
@@ -450,7 +1060,6 @@ pub mod pallet {
 				sender.clone(),
 				owner,
 				collection_id,
-				royalty_recipient,
 				royalty,
 				metadata,
 				transferable,
@@ -483,6 +1092,12 @@ pub mod pallet {
 		}
 
 		/// Create a collection
+		///
+		/// Parameters:
+		/// - `metadata`: Arbitrary data about a collection, e.g. IPFS hash
+		/// - `max`: Optional maximum number of NFTs in the collection
+		/// - `symbol`: Collection symbol
+		/// - `royalty`: Optional default royalty of NFTs minted without their own royalty
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
 		#[transactional]
 		pub fn create_collection(
@@ -490,10 +1105,12 @@ pub mod pallet {
 			metadata: BoundedVec<u8, T::StringLimit>,
 			max: Option<u32>,
 			symbol: BoundedCollectionSymbolOf<T>,
+			royalty: Option<RoyaltyInfoOf<T>>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 
-			let collection_id = Self::collection_create(sender.clone(), metadata, max, symbol)?;
+			let collection_id =
+				Self::collection_create(sender.clone(), metadata, max, symbol, royalty)?;
 
 			pallet_uniques::Pallet::<T>::do_create_collection(
 				collection_id,
@@ -513,7 +1130,10 @@ pub mod pallet {
 		}
 
 		/// burn nft
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads_writes(1, 1) + Pallet::<T>::traversal_weight(1) +
+				Pallet::<T>::burn_weight(T::MaxDescendants::get() as u64 + 1)
+		)]
 		#[transactional]
 		pub fn burn_nft(
 			origin: OriginFor<T>,
@@ -523,13 +1143,46 @@ pub mod pallet {
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id)?;
-			// Check ownership
-			ensure!(sender == root_owner, Error::<T>::NoPermission);
+			// Check ownership or approval
+			Self::ensure_owner_or_approved(&sender, &root_owner, collection_id, nft_id)?;
 			let (_collection_id, nft_id) = Self::nft_burn(collection_id, nft_id, max_burns)?;
 
 			pallet_uniques::Pallet::<T>::do_burn(collection_id, nft_id, |_, _| Ok(()))?;
 
-			Self::deposit_event(Event::NFTBurned { owner: sender, nft_id });
+			Self::deposit_event(Event::NFTBurned { owner: root_owner, nft_id });
+			Ok(())
+		}
+
+		/// Burn an NFT without waiting for its descendants to be deleted
+		///
+		/// The NFT is burned right away, while its descendants, along with their resources and
+		/// properties, are deleted over the next blocks with the weight left over in each, each
+		/// emitting `NFTBurned` as it is deleted. Suited to trees too large for `burn_nft`.
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, root owner of the NFT or an approved operator
+		/// - `collection_id`: collection id of the nft to be burned
+		/// - `nft_id`: nft id of the nft to be burned
+		#[pallet::weight(
+			Pallet::<T>::burn_weight(1) + Pallet::<T>::traversal_weight(1) +
+				T::DbWeight::get()
+					.reads_writes(4, 5)
+					.saturating_mul(T::MaxChildrenPerNft::get() as u64)
+		)]
+		#[transactional]
+		pub fn burn_nft_lazy(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let (root_owner, _) = Pallet::<T>::lookup_root_owner(collection_id, nft_id)?;
+			// Check ownership or approval
+			Self::ensure_owner_or_approved(&sender, &root_owner, collection_id, nft_id)?;
+
+			Self::nft_burn_lazily(root_owner.clone(), collection_id, nft_id)?;
+
+			Self::deposit_event(Event::NFTBurned { owner: root_owner, nft_id });
 			Ok(())
 		}
 
@@ -561,11 +1214,14 @@ pub mod pallet {
 		/// Transfers a NFT from an Account or NFT A to another Account or NFT B
 		///
 		/// Parameters:
-		/// - `origin`: sender of the transaction
+		/// - `origin`: sender of the transaction, root owner of the NFT or an approved operator
 		/// - `collection_id`: collection id of the nft to be transferred
 		/// - `nft_id`: nft id of the nft to be transferred
 		/// - `new_owner`: new owner of the nft which can be either an account or a NFT
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads_writes(1, 1) + Pallet::<T>::traversal_weight(3) +
+				Pallet::<T>::subtree_weight()
+		)]
 		#[transactional]
 		pub fn send(
 			origin: OriginFor<T>,
@@ -603,7 +1259,10 @@ pub mod pallet {
 		/// - `nft_id`: nft id of the nft to be accepted
 		/// - `new_owner`: either origin's account ID or origin-owned NFT, whichever the NFT was
 		///   sent to
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads_writes(1, 1) + Pallet::<T>::traversal_weight(3) +
+				Pallet::<T>::subtree_weight()
+		)]
 		#[transactional]
 		pub fn accept_nft(
 			origin: OriginFor<T>,
@@ -632,26 +1291,251 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Rejects an NFT sent from another account to self or owned NFT
+		/// Rejects an NFT sent from another account to self or owned NFT. The rejected NFT,
+		/// along with its descendants, is returned to the account or NFT that sent it, unless
+		/// `burn` is set, in which case it is burned instead.
 		///
 		/// Parameters:
 		/// - `origin`: sender of the transaction
-		/// - `collection_id`: collection id of the nft to be accepted
-		/// - `nft_id`: nft id of the nft to be accepted
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		/// - `collection_id`: collection id of the nft to be rejected
+		/// - `nft_id`: nft id of the nft to be rejected
+		/// - `burn`: burn the rejected nft instead of returning it
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads_writes(1, 1) + Pallet::<T>::traversal_weight(2) +
+				Pallet::<T>::subtree_weight() +
+				Pallet::<T>::burn_weight(T::MaxDescendants::get() as u64 + 1)
+		)]
 		#[transactional]
 		pub fn reject_nft(
 			origin: OriginFor<T>,
 			collection_id: CollectionId,
 			nft_id: NftId,
+			burn: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let returned_to = if burn {
+				let max_recursions = T::MaxRecursions::get();
+				Self::nft_reject_and_burn(sender.clone(), collection_id, nft_id, max_recursions)?;
+
+				pallet_uniques::Pallet::<T>::do_burn(collection_id, nft_id, |_, _| Ok(()))?;
+				None
+			} else {
+				let (returned_to_account, returned_to) =
+					Self::nft_reject(sender.clone(), collection_id, nft_id)?;
+
+				pallet_uniques::Pallet::<T>::do_transfer(
+					collection_id,
+					nft_id,
+					returned_to_account,
+					|_class_details, _details| Ok(()),
+				)?;
+				Some(returned_to)
+			};
+
+			Self::deposit_event(Event::NFTRejected { sender, collection_id, nft_id, returned_to });
+			Ok(())
+		}
+
+		/// Accepts a list of pending NFTs where they already are, under their current parent NFT
+		/// or account, with the same root owner checks as `accept_nft`
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, root owner of every NFT
+		/// - `nfts`: pending NFTs to accept, as (collection id, nft id) pairs
+		#[pallet::weight(
+			(10_000 +
+				T::DbWeight::get().reads_writes(1, 1) +
+				Pallet::<T>::traversal_weight(3) +
+				Pallet::<T>::subtree_weight())
+				.saturating_mul(nfts.len() as u64)
+		)]
+		#[transactional]
+		pub fn accept_nfts(
+			origin: OriginFor<T>,
+			nfts: BoundedVec<(CollectionId, NftId), T::MaxBatchAccept>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			for (collection_id, nft_id) in nfts {
+				let recipient = Self::accept_in_place(sender.clone(), collection_id, nft_id)?;
+
+				Self::deposit_event(Event::NFTAccepted {
+					sender: sender.clone(),
+					recipient,
+					collection_id,
+					nft_id,
+				});
+			}
+			Ok(())
+		}
+
+		/// Accepts the pending children of an NFT, at most `MaxBatchAccept` of them per call
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, root owner of the parent NFT
+		/// - `collection_id`: collection id of the parent nft
+		/// - `nft_id`: nft id of the parent nft
+		#[pallet::weight(
+			(10_000 +
+				T::DbWeight::get().reads_writes(1, 1) +
+				Pallet::<T>::traversal_weight(3) +
+				Pallet::<T>::subtree_weight())
+				.saturating_mul(T::MaxBatchAccept::get() as u64)
+		)]
+		#[transactional]
+		pub fn accept_all_pending(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
+			let (root_owner, _) = Self::lookup_root_owner(collection_id, nft_id)?;
+			ensure!(sender == root_owner, Error::<T>::NoPermission);
 
-			let max_recursions = T::MaxRecursions::get();
-			let (sender, collection_id, nft_id) =
-				Self::nft_reject(sender, collection_id, nft_id, max_recursions)?;
+			let pending_children: Vec<(CollectionId, NftId)> =
+				Children::<T>::iter_key_prefix((collection_id, nft_id))
+					.filter(|(child_collection_id, child_nft_id)| {
+						PendingNfts::<T>::contains_key(child_collection_id, child_nft_id)
+					})
+					.take(T::MaxBatchAccept::get() as usize)
+					.collect();
+
+			for (child_collection_id, child_nft_id) in pending_children {
+				let recipient =
+					Self::accept_in_place(sender.clone(), child_collection_id, child_nft_id)?;
+
+				Self::deposit_event(Event::NFTAccepted {
+					sender: sender.clone(),
+					recipient,
+					collection_id: child_collection_id,
+					nft_id: child_nft_id,
+				});
+			}
+			Ok(())
+		}
 
-			Self::deposit_event(Event::NFTRejected { sender, collection_id, nft_id });
+		/// Change the order in which the children of an NFT are listed
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, root owner of the NFT
+		/// - `collection_id`: collection id of the parent nft
+		/// - `nft_id`: nft id of the parent nft
+		/// - `order`: every child of the NFT, pending or not, in the new order
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[transactional]
+		pub fn reorder_children(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			order: BoundedVec<(CollectionId, NftId), T::MaxChildrenPerNft>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::children_reorder(sender, collection_id, nft_id, order)?;
+
+			Self::deposit_event(Event::ChildrenReordered { collection_id, nft_id });
+			Ok(())
+		}
+
+		/// Set the policy for NFTs that other accounts send or mint under the sender's NFTs
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction
+		/// - `policy`: the new policy, `None` to clear it and have every incoming NFT be pending
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[transactional]
+		pub fn set_incoming_policy(
+			origin: OriginFor<T>,
+			policy: Option<IncomingPolicyOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			IncomingPolicies::<T>::set(&sender, policy.clone());
+
+			Self::deposit_event(Event::IncomingPolicySet { account: sender, policy });
+			Ok(())
+		}
+
+		/// Approve an operator to send or burn an NFT, replacing any previous approval. The
+		/// approval is cleared when the NFT is sent or changes root owner
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, root owner of the NFT
+		/// - `collection_id`: collection id of the nft
+		/// - `nft_id`: nft id of the nft
+		/// - `operator`: account approved to send or burn the nft
+		/// - `expiry`: last block the approval is valid at, `None` for no expiry
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads_writes(1, 1) + Pallet::<T>::traversal_weight(1)
+		)]
+		#[transactional]
+		pub fn approve(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			operator: T::AccountId,
+			expiry: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::nft_approve(sender.clone(), collection_id, nft_id, operator.clone(), expiry)?;
+
+			Self::deposit_event(Event::Approved {
+				owner: sender,
+				collection_id,
+				nft_id,
+				operator,
+				expiry,
+			});
+			Ok(())
+		}
+
+		/// Cancel the approval of the operator of an NFT
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction, root owner of the NFT
+		/// - `collection_id`: collection id of the nft
+		/// - `nft_id`: nft id of the nft
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads_writes(1, 1) + Pallet::<T>::traversal_weight(1)
+		)]
+		#[transactional]
+		pub fn cancel_approval(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::nft_cancel_approval(sender.clone(), collection_id, nft_id)?;
+
+			Self::deposit_event(Event::ApprovalCancelled { owner: sender, collection_id, nft_id });
+			Ok(())
+		}
+
+		/// Approve or revoke an operator for every NFT the sender root-owns, now or later
+		///
+		/// Parameters:
+		/// - `origin`: sender of the transaction
+		/// - `operator`: account approved to send or burn the sender's nfts
+		/// - `approved`: whether the operator is approved or revoked
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[transactional]
+		pub fn set_approval_for_all(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+			approved: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			if approved {
+				OperatorApprovals::<T>::insert(&sender, &operator, ());
+			} else {
+				OperatorApprovals::<T>::remove(&sender, &operator);
+			}
+
+			Self::deposit_event(Event::ApprovalForAll { owner: sender, operator, approved });
 			Ok(())
 		}
 
@@ -693,7 +1577,9 @@ pub mod pallet {
 		}
 
 		/// set a custom value on an NFT
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads_writes(1, 1) + Pallet::<T>::properties_count_weight()
+		)]
 		#[transactional]
 		pub fn set_property(
 			origin: OriginFor<T>,
@@ -709,6 +1595,160 @@ pub mod pallet {
 			Self::deposit_event(Event::PropertySet { collection_id, maybe_nft_id, key, value });
 			Ok(())
 		}
+
+		/// Remove a property from a collection or NFT
+		///
+		/// Parameters:
+		/// - `collection_id`: Collection of the property
+		/// - `maybe_nft_id`: NFT of the property, `None` for a collection property
+		/// - `key`: Key of the property to remove
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[transactional]
+		pub fn remove_property(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_id: CollectionId,
+			maybe_nft_id: Option<NftId>,
+			key: KeyLimitOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::property_remove(sender, collection_id, maybe_nft_id, key.clone())?;
+
+			Self::deposit_event(Event::PropertyRemoved { collection_id, maybe_nft_id, key });
+			Ok(())
+		}
+
+		/// Set several properties on a collection or NFT at once
+		///
+		/// Parameters:
+		/// - `collection_id`: Collection of the properties
+		/// - `maybe_nft_id`: NFT of the properties, `None` for collection properties
+		/// - `properties`: Key and value pairs to set, fails as a whole if any of them fails
+		#[pallet::weight(
+			(10_000 + T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(Pallet::<T>::properties_count_weight())
+				.saturating_mul(properties.len() as u64)
+		)]
+		#[transactional]
+		pub fn set_properties(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_id: CollectionId,
+			maybe_nft_id: Option<NftId>,
+			properties: BoundedVec<(KeyLimitOf<T>, ValueLimitOf<T>), T::MaxPropertiesBatch>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			for (key, value) in properties {
+				Self::property_set(
+					sender.clone(),
+					collection_id,
+					maybe_nft_id,
+					key.clone(),
+					value.clone(),
+				)?;
+				Self::deposit_event(Event::PropertySet { collection_id, maybe_nft_id, key, value });
+			}
+			Ok(())
+		}
+
+		/// Declare or clear the type of the values of a property key in a collection
+		///
+		/// Once declared, property values of the key that do not match the type are rejected.
+		/// Values set before the declaration are not checked again.
+		///
+		/// Parameters:
+		/// - `collection_id`: Collection to configure, must be issued by the sender
+		/// - `key`: Property key the type applies to
+		/// - `property_type`: Type of the values, or `None` to accept any value again
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[transactional]
+		pub fn set_property_schema(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_id: CollectionId,
+			key: KeyLimitOf<T>,
+			property_type: Option<PropertyType>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::property_set_schema(sender, collection_id, key.clone(), property_type)?;
+
+			Self::deposit_event(Event::PropertySchemaSet { collection_id, key, property_type });
+			Ok(())
+		}
+
+		/// Set a property in the owner namespace of an NFT
+		///
+		/// Owner properties are kept apart from the issuer's properties, so the root owner can
+		/// store its own data without being able to overwrite issuer attributes. The deposit is
+		/// reserved from the sender.
+		///
+		/// Parameters:
+		/// - `collection_id`: Collection of the NFT
+		/// - `nft_id`: NFT to set the property on, must be root-owned by the sender
+		/// - `key`: Key of the property
+		/// - `value`: Value of the property
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads_writes(1, 1) + Pallet::<T>::properties_count_weight()
+		)]
+		#[transactional]
+		pub fn set_owner_property(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_id: CollectionId,
+			#[pallet::compact] nft_id: NftId,
+			key: KeyLimitOf<T>,
+			value: ValueLimitOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::owner_property_set(sender, collection_id, nft_id, key.clone(), value.clone())?;
+
+			Self::deposit_event(Event::OwnerPropertySet { collection_id, nft_id, key, value });
+			Ok(())
+		}
+
+		/// Remove a property from the owner namespace of an NFT
+		///
+		/// Parameters:
+		/// - `collection_id`: Collection of the NFT
+		/// - `nft_id`: NFT to remove the property from, must be root-owned by the sender
+		/// - `key`: Key of the property to remove
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[transactional]
+		pub fn remove_owner_property(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_id: CollectionId,
+			#[pallet::compact] nft_id: NftId,
+			key: KeyLimitOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::owner_property_remove(sender, collection_id, nft_id, key.clone())?;
+
+			Self::deposit_event(Event::OwnerPropertyRemoved { collection_id, nft_id, key });
+			Ok(())
+		}
+
+		/// Set who may write the properties of a key in a collection
+		///
+		/// Parameters:
+		/// - `collection_id`: Collection to configure, must be issued by the sender
+		/// - `key`: Property key the permission applies to
+		/// - `permission`: New permission, an `Immutable` key can no longer be reconfigured
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[transactional]
+		pub fn set_property_permission(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_id: CollectionId,
+			key: KeyLimitOf<T>,
+			permission: PropertyPermission,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::property_set_permission(sender, collection_id, key.clone(), permission)?;
+
+			Self::deposit_event(Event::PropertyPermissionSet { collection_id, key, permission });
+			Ok(())
+		}
 		/// lock collection
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
 		#[transactional]
@@ -724,9 +1764,88 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Create basic resource
+		/// Set or clear the default royalty of a collection
+		///
+		/// NFTs minted without a royalty of their own inherit the collection default royalty.
+		///
+		/// Parameters:
+		/// - `collection_id`: Collection to update, must be issued by the sender
+		/// - `royalty`: New default royalty, or `None` to remove it
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
 		#[transactional]
+		pub fn set_collection_royalty(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			royalty: Option<RoyaltyInfoOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::collection_set_royalty(sender, collection_id, royalty.clone())?;
+
+			Self::deposit_event(Event::CollectionRoyaltySet { collection_id, royalty });
+			Ok(())
+		}
+
+		/// Update the metadata of an NFT
+		///
+		/// Parameters:
+		/// - `collection_id`: Collection of the NFT, must be issued by the sender
+		/// - `nft_id`: NFT to update
+		/// - `metadata`: New metadata, e.g. IPFS hash
+		/// - `freeze`: Whether to freeze the metadata, which can never be undone
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[transactional]
+		pub fn set_nft_metadata(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			metadata: BoundedVec<u8, T::StringLimit>,
+			freeze: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::nft_set_metadata(sender, collection_id, nft_id, metadata.clone(), freeze)?;
+
+			Self::deposit_event(Event::NftMetadataSet {
+				collection_id,
+				nft_id,
+				metadata,
+				frozen: freeze,
+			});
+			Ok(())
+		}
+
+		/// Update the metadata of a collection
+		///
+		/// Parameters:
+		/// - `collection_id`: Collection to update, must be issued by the sender
+		/// - `metadata`: New metadata, e.g. IPFS hash
+		/// - `freeze`: Whether to freeze the metadata, which can never be undone
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[transactional]
+		pub fn set_collection_metadata(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			metadata: BoundedVec<u8, T::StringLimit>,
+			freeze: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::collection_set_metadata(sender, collection_id, metadata.clone(), freeze)?;
+
+			Self::deposit_event(Event::CollectionMetadataSet {
+				collection_id,
+				metadata,
+				frozen: freeze,
+			});
+			Ok(())
+		}
+
+		/// Create basic resource
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads_writes(1, 1) + Pallet::<T>::resources_count_weight()
+		)]
+		#[transactional]
 		pub fn add_basic_resource(
 			origin: OriginFor<T>,
 			collection_id: CollectionId,
@@ -748,7 +1867,9 @@ pub mod pallet {
 		}
 
 		/// Create composable resource
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads_writes(1, 1) + Pallet::<T>::resources_count_weight()
+		)]
 		#[transactional]
 		pub fn add_composable_resource(
 			origin: OriginFor<T>,
@@ -771,7 +1892,9 @@ pub mod pallet {
 		}
 
 		/// Create slot resource
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads_writes(1, 1) + Pallet::<T>::resources_count_weight()
+		)]
 		#[transactional]
 		pub fn add_slot_resource(
 			origin: OriginFor<T>,
@@ -793,6 +1916,58 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Add the same resource to many NFTs of a collection in one call
+		///
+		/// Parameters:
+		/// - `collection_id`: The collection of the targeted NFTs
+		/// - `targets`: Either an explicit list of NFTs or an inclusive id range, at most
+		///   `MaxBulkResources` NFTs long. Burned ids within a range are skipped.
+		/// - `resource`: The resource added to every targeted NFT
+		///
+		/// As with the single-NFT calls, the resource is pending on every NFT whose root owner is
+		/// not the issuer.
+		#[pallet::weight(
+			(10_000 + T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(Pallet::<T>::resources_count_weight())
+				.saturating_mul(T::MaxBulkResources::get() as u64)
+		)]
+		#[transactional]
+		pub fn add_resource_to_nfts(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			targets: ResourceTargetsOf<T>,
+			resource: ResourceTypeOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let nft_ids: Vec<NftId> = match targets {
+				ResourceTargets::Nfts(nfts) => nfts.into_inner(),
+				ResourceTargets::Range { first, last } => {
+					ensure!(
+						first <= last && last - first < T::MaxBulkResources::get(),
+						Error::<T>::InvalidResourceTargets
+					);
+					(first..=last)
+						.filter(|nft_id| Nfts::<T>::contains_key(collection_id, nft_id))
+						.collect()
+				},
+			};
+			ensure!(!nft_ids.is_empty(), Error::<T>::InvalidResourceTargets);
+
+			for nft_id in nft_ids {
+				let resource_id = Self::resource_add(
+					sender.clone(),
+					collection_id,
+					nft_id,
+					resource.clone(),
+					false,
+				)?;
+
+				Self::deposit_event(Event::ResourceAdded { nft_id, resource_id });
+			}
+			Ok(())
+		}
+
 		/// accept the addition of a new resource to an existing NFT
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
 		#[transactional]
@@ -821,11 +1996,32 @@ pub mod pallet {
 					Ok(())
 				},
 			)?;
+			PendingInbox::<T>::remove(
+				&owner,
+				PendingItem::ResourceAddition { collection_id, nft_id, resource_id },
+			);
 
 			Self::deposit_event(Event::ResourceAccepted { nft_id, resource_id });
 			Ok(())
 		}
 
+		/// reject the addition of a pending resource to an existing NFT
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[transactional]
+		pub fn reject_resource(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			resource_id: ResourceId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::reject(sender, collection_id, nft_id, resource_id)?;
+
+			Self::deposit_event(Event::ResourceRejected { nft_id, resource_id });
+			Ok(())
+		}
+
 		/// remove resource
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
 		#[transactional]
@@ -860,6 +2056,119 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Replace a resource of an NFT, keeping its resource ID and priority
+		///
+		/// If the issuer doesn't root-own the NFT, the replacement is pending until the root
+		/// owner accepts it with `accept_resource_replacement`, unless the resource itself is
+		/// still pending, in which case it is replaced in place.
+		///
+		/// Parameters:
+		/// - `collection_id`: Collection of the NFT, must be issued by the sender
+		/// - `nft_id`: NFT owning the resource
+		/// - `resource_id`: Resource to replace
+		/// - `resource`: New resource
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[transactional]
+		pub fn replace_resource(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			resource_id: ResourceId,
+			resource: ResourceTypeOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::resource_replace(sender, collection_id, nft_id, resource_id, resource)?;
+
+			Self::deposit_event(Event::ResourceReplaced { nft_id, resource_id });
+			Ok(())
+		}
+
+		/// accept the replacement of a resource of an existing NFT
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[transactional]
+		pub fn accept_resource_replacement(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			resource_id: ResourceId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::accept_replacement(sender, collection_id, nft_id, resource_id)?;
+
+			Self::deposit_event(Event::ResourceReplacementAccepted { nft_id, resource_id });
+			Ok(())
+		}
+
+		/// Add a resource shared by every NFT of a collection (issuer only)
+		///
+		/// The resource is stored once for the whole collection and displayed by every NFT that
+		/// didn't opt out of it, current and future ones alike. Shared resources don't need to be
+		/// accepted.
+		#[pallet::weight(
+			10_000 + T::DbWeight::get().reads_writes(1, 1) + Pallet::<T>::resources_count_weight()
+		)]
+		#[transactional]
+		pub fn add_collection_resource(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			resource: ResourceTypeOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let resource_id = Self::collection_resource_add(sender, collection_id, resource)?;
+
+			Self::deposit_event(Event::CollectionResourceAdded { collection_id, resource_id });
+			Ok(())
+		}
+
+		/// Remove a shared resource from a collection, and with it the opt-outs of its NFTs
+		/// (issuer only)
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[transactional]
+		pub fn remove_collection_resource(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			resource_id: ResourceId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::collection_resource_remove(sender, collection_id, resource_id)?;
+
+			Self::deposit_event(Event::CollectionResourceRemoved { collection_id, resource_id });
+			Ok(())
+		}
+
+		/// Opt an NFT out of (or back into) a shared resource of its collection (root owner only)
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[transactional]
+		pub fn set_collection_resource_opt_out(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			nft_id: NftId,
+			resource_id: ResourceId,
+			opt_out: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::collection_resource_set_opt_out(
+				sender,
+				collection_id,
+				nft_id,
+				resource_id,
+				opt_out,
+			)?;
+
+			Self::deposit_event(Event::CollectionResourceOptOutSet {
+				collection_id,
+				nft_id,
+				resource_id,
+				opt_out,
+			});
+			Ok(())
+		}
+
 		/// set a different order of resource priority
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
 		#[transactional]
@@ -0,0 +1,523 @@
+// Copyright (C) 2021-2022 RMRK
+// This file is part of rmrk-core.
+// License: Apache 2.0 modified by RMRK, see LICENSE.md
+
+//! Storage migrations, run in order from `on_runtime_upgrade`.
+//!
+//! Each migration walks every entry of the storage it rebuilds within the single block of the
+//! upgrade, which assumes a chain holding no more than a few thousand NFTs, as on the chains
+//! running this pallet so far. A chain beyond that must run them as multi-block migrations
+//! instead. Run the upgrade with `try-runtime` against a copy of the chain state first: the
+//! `try_runtime` checks below verify what the migrations rebuild.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, StorageVersion},
+};
+
+/// Migrates royalties from a single recipient to a list of recipient shares.
+pub mod v1 {
+	use super::*;
+
+	/// Royalty information before v1 (single recipient and amount)
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct OldRoyaltyInfo<AccountId> {
+		pub recipient: AccountId,
+		pub amount: Permill,
+	}
+
+	/// Nft info before v1
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct OldNftInfo<AccountId, BoundedString> {
+		pub owner: AccountIdOrCollectionNftTuple<AccountId>,
+		pub royalty: Option<OldRoyaltyInfo<AccountId>>,
+		pub metadata: BoundedString,
+		pub equipped: bool,
+		pub pending: bool,
+		pub transferable: bool,
+	}
+
+	/// Collection info before v1 (no default royalty)
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct OldCollectionInfo<BoundedString, BoundedSymbol, AccountId> {
+		pub issuer: AccountId,
+		pub metadata: BoundedString,
+		pub max: Option<u32>,
+		pub symbol: BoundedSymbol,
+		pub nfts_count: u32,
+	}
+
+	pub type OldNftInfoOf<T> = OldNftInfo<<T as frame_system::Config>::AccountId, StringLimitOf<T>>;
+
+	pub type OldCollectionInfoOf<T> = OldCollectionInfo<
+		StringLimitOf<T>,
+		BoundedCollectionSymbolOf<T>,
+		<T as frame_system::Config>::AccountId,
+	>;
+
+	/// Converts a single recipient royalty into a single share royalty.
+	///
+	/// The royalty is dropped if the runtime allows no royalty recipients at all.
+	fn migrate_royalty<T: Config>(
+		royalty: OldRoyaltyInfo<T::AccountId>,
+	) -> Option<RoyaltyInfoOf<T>> {
+		let shares: BoundedRoyaltySharesOf<T> =
+			sp_std::vec![(royalty.recipient, royalty.amount)].try_into().ok()?;
+		Some(RoyaltyInfo { shares })
+	}
+
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 1 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut translated = 0u64;
+		Collections::<T>::translate::<OldCollectionInfoOf<T>, _>(|_, old| {
+			translated += 1;
+			Some(CollectionInfo {
+				issuer: old.issuer,
+				metadata: old.metadata,
+				max: old.max,
+				symbol: old.symbol,
+				nfts_count: old.nfts_count,
+				royalty: None,
+			})
+		});
+		Nfts::<T>::translate::<OldNftInfoOf<T>, _>(|_, _, old| {
+			translated += 1;
+			Some(NftInfo {
+				owner: old.owner,
+				royalty: old.royalty.and_then(migrate_royalty::<T>),
+				metadata: old.metadata,
+				equipped: old.equipped,
+				pending: old.pending,
+				transferable: old.transferable,
+			})
+		});
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+}
+
+/// Turns `EquippableBases` into a count of the composable resources using each base.
+pub mod v2 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 2 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut reads = 0u64;
+		let mut translated = 0u64;
+		EquippableBases::<T>::translate::<(), _>(|(collection_id, nft_id, base_id), _| {
+			translated += 1;
+			let count = Resources::<T>::iter_prefix_values((collection_id, nft_id))
+				.inspect(|_| reads += 1)
+				.filter(
+					|resource| matches!(&resource.resource, ResourceTypes::Composable(r) if r.base == base_id),
+				)
+				.count() as u32;
+			// Bases left behind by removed resources are dropped
+			if count > 0 {
+				Some(count)
+			} else {
+				None
+			}
+		});
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(reads + translated + 1, translated + 1)
+	}
+}
+
+/// Populates the `NftsByRootOwner` index from the existing NFTs.
+pub mod v3 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight
+	where
+		T: pallet_uniques::Config<CollectionId = CollectionId, ItemId = NftId>,
+	{
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 3 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut reads = 0u64;
+		let mut indexed = 0u64;
+		for (collection_id, nft_id, _) in Nfts::<T>::iter() {
+			reads += 1;
+			if let Ok((root_owner, _)) = Pallet::<T>::lookup_root_owner(collection_id, nft_id) {
+				NftsByRootOwner::<T>::insert(root_owner, (collection_id, nft_id), ());
+				indexed += 1;
+			}
+		}
+
+		StorageVersion::new(3).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(reads + 1, indexed + 1)
+	}
+}
+
+/// Populates the `PendingInbox` index from the existing pending NFTs and resources.
+pub mod v4 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight
+	where
+		T: pallet_uniques::Config<CollectionId = CollectionId, ItemId = NftId>,
+	{
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 4 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut reads = 0u64;
+		let mut indexed = 0u64;
+		for (collection_id, nft_id, _) in Nfts::<T>::iter() {
+			reads += 1;
+			if let Ok((root_owner, _)) = Pallet::<T>::lookup_root_owner(collection_id, nft_id) {
+				for item in Pallet::<T>::pending_items(collection_id, nft_id) {
+					PendingInbox::<T>::insert(&root_owner, item, ());
+					indexed += 1;
+				}
+			}
+		}
+
+		StorageVersion::new(4).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(reads + 1, indexed + 1)
+	}
+}
+
+/// Records the block at which pending NFTs became pending, starting their timeout at the
+/// upgrade.
+pub mod v5 {
+	use super::*;
+
+	/// Pending nft info before v5 (no block)
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct OldPendingNftInfo<AccountId> {
+		pub sender: AccountId,
+		pub previous_owner: AccountIdOrCollectionNftTuple<AccountId>,
+	}
+
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 5 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		let mut translated = 0u64;
+		PendingNfts::<T>::translate::<OldPendingNftInfo<T::AccountId>, _>(
+			|collection_id, nft_id, old| {
+				translated += 1;
+				PendingNftQueue::<T>::insert(now, (collection_id, nft_id), ());
+				Some(PendingNftInfo {
+					sender: old.sender,
+					previous_owner: old.previous_owner,
+					since: now,
+				})
+			},
+		);
+		PendingSweepCursor::<T>::put(now);
+
+		StorageVersion::new(5).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(translated + 2, translated * 2 + 2)
+	}
+}
+
+/// Populates `ChildOrder` from the existing children. Children beyond `MaxChildrenPerNft` are
+/// kept but left out of the order.
+pub mod v6 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 6 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut reads = 0u64;
+		let mut writes = 0u64;
+		for (parent, child, _) in Children::<T>::iter() {
+			reads += 1;
+			ChildOrder::<T>::mutate(parent, |children| {
+				if children.try_push(child).is_ok() {
+					writes += 1;
+				}
+			});
+		}
+
+		StorageVersion::new(6).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(reads * 2 + 1, writes + 1)
+	}
+}
+
+/// Populates `NftDepth` from the existing NFTs. NFTs already nested deeper than
+/// `MaxRecursions` keep their depth, so they can only be moved up.
+pub mod v7 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 7 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut reads = 0u64;
+		let mut writes = 0u64;
+		for (collection_id, nft_id, _) in Nfts::<T>::iter() {
+			reads += 1;
+			let mut depth = 0u32;
+			let mut current = (collection_id, nft_id);
+			while let Some(parent) = pallet_uniques::Pallet::<T>::owner(current.0, current.1)
+				.and_then(Pallet::<T>::decode_nft_account_id::<T::AccountId>)
+			{
+				reads += 1;
+				depth += 1;
+				current = parent;
+			}
+			if depth > 0 {
+				NftDepth::<T>::insert((collection_id, nft_id), depth);
+				writes += 1;
+			}
+		}
+
+		StorageVersion::new(7).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(reads + 1, writes + 1)
+	}
+}
+
+/// Records the origin of the NFTs that were already pending before `PendingNfts` existed, so
+/// they can be rejected, accepted in bulk and time out like any other pending NFT. Such NFTs
+/// still list their previous owner as owner: they are moved to the NFT they were sent to and
+/// are returned to the root owner of their previous owner if rejected.
+pub mod v8 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight
+	where
+		T: pallet_uniques::Config<CollectionId = CollectionId, ItemId = NftId>,
+	{
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 8 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		let mut reads = 0u64;
+		let mut writes = 0u64;
+		let unrecorded: Vec<_> = Nfts::<T>::iter()
+			.inspect(|_| reads += 1)
+			.filter(|(collection_id, nft_id, nft)| {
+				nft.pending && !PendingNfts::<T>::contains_key(collection_id, nft_id)
+			})
+			.collect();
+		for (collection_id, nft_id, mut nft) in unrecorded {
+			// The NFT is owned in uniques by the NFT or account it was sent to
+			let owner = match pallet_uniques::Pallet::<T>::owner(collection_id, nft_id) {
+				Some(owner) => owner,
+				None => continue,
+			};
+			let new_owner = match Pallet::<T>::decode_nft_account_id::<T::AccountId>(owner.clone())
+			{
+				Some((cid, nid)) => AccountIdOrCollectionNftTuple::CollectionAndNftTuple(cid, nid),
+				None => AccountIdOrCollectionNftTuple::AccountId(owner),
+			};
+			let recipient = match Pallet::<T>::lookup_root_owner(collection_id, nft_id) {
+				Ok((recipient, _)) => recipient,
+				Err(_) => continue,
+			};
+			// The sender is the root owner of the previous owner, or the recipient if the
+			// previous owner no longer exists
+			let sender = match &nft.owner {
+				AccountIdOrCollectionNftTuple::AccountId(account) => account.clone(),
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(cid, nid) =>
+					Pallet::<T>::lookup_root_owner(*cid, *nid)
+						.map_or_else(|_| recipient.clone(), |(root, _)| root),
+			};
+			reads += 3;
+
+			let previous_owner = sp_std::mem::replace(&mut nft.owner, new_owner);
+			Nfts::<T>::insert(collection_id, nft_id, nft);
+			PendingNfts::<T>::insert(
+				collection_id,
+				nft_id,
+				PendingNftInfo { sender, previous_owner, since: now },
+			);
+			PendingNftQueue::<T>::insert(now, (collection_id, nft_id), ());
+			PendingInbox::<T>::insert(recipient, PendingItem::Nft { collection_id, nft_id }, ());
+			writes += 4;
+		}
+
+		StorageVersion::new(8).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(reads + 1, writes + 1)
+	}
+}
+
+/// Queues the NFTs awaiting a lazy burn in `LazyBurnQueue`, and drops them from the indexes of
+/// their root owners.
+pub mod v9 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 9 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut queued = 0u64;
+		let lazy_burns: Vec<_> = LazyBurns::<T>::iter().collect();
+		for (nft, root_owner) in lazy_burns {
+			Pallet::<T>::queue_lazy_burn(&root_owner, nft);
+			queued += 1;
+		}
+
+		StorageVersion::new(9).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(queued * 2 + 1, queued * 4 + 1)
+	}
+}
+
+/// Populates `DescendantCount` from the existing trees of NFTs.
+pub mod v10 {
+	use super::*;
+
+	pub fn migrate<T: Config>() -> Weight
+	where
+		T: pallet_uniques::Config<CollectionId = CollectionId, ItemId = NftId>,
+	{
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 10 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut reads = 0u64;
+		let mut writes = 0u64;
+		let parents: Vec<_> = Children::<T>::iter_keys().map(|(parent, _)| parent).collect();
+		for parent in parents {
+			// Each child counts as a descendant of its parent and of every NFT above it
+			for ancestor in Pallet::<T>::self_and_ancestors(parent) {
+				DescendantCount::<T>::mutate(ancestor, |count| *count = count.saturating_add(1));
+				reads += 2;
+				writes += 1;
+			}
+		}
+
+		StorageVersion::new(10).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(reads + 1, writes + 1)
+	}
+}
+
+/// Checks run by `try-runtime` around the migrations.
+#[cfg(feature = "try-runtime")]
+pub mod try_runtime {
+	use super::*;
+	use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+	/// Records the number of collections and NFTs, which the migrations must keep.
+	pub fn pre_upgrade<T: Config>() -> Result<(), &'static str>
+	where
+		T: pallet_uniques::Config<CollectionId = CollectionId, ItemId = NftId>,
+	{
+		ensure!(
+			Pallet::<T>::on_chain_storage_version() <= Pallet::<T>::current_storage_version(),
+			"rmrk-core storage is newer than the runtime"
+		);
+		Pallet::<T>::set_temp_storage(Collections::<T>::iter_keys().count() as u32, "collections");
+		Pallet::<T>::set_temp_storage(Nfts::<T>::iter_keys().count() as u32, "nfts");
+		Ok(())
+	}
+
+	/// Checks that no collection or NFT got lost and that the indexes built by the migrations
+	/// agree with the NFTs.
+	pub fn post_upgrade<T: Config>() -> Result<(), &'static str>
+	where
+		T: pallet_uniques::Config<CollectionId = CollectionId, ItemId = NftId>,
+	{
+		ensure!(
+			Pallet::<T>::on_chain_storage_version() == Pallet::<T>::current_storage_version(),
+			"rmrk-core storage version was not updated"
+		);
+		// v1: every collection and NFT decodes in the current layout
+		let collections = Collections::<T>::iter_values().count() as u32;
+		ensure!(
+			Pallet::<T>::get_temp_storage::<u32>("collections") == Some(collections),
+			"collections were lost"
+		);
+		let nfts = Nfts::<T>::iter_values().count() as u32;
+		ensure!(Pallet::<T>::get_temp_storage::<u32>("nfts") == Some(nfts), "nfts were lost");
+
+		for (collection_id, nft_id, nft) in Nfts::<T>::iter() {
+			let nft_key = (collection_id, nft_id);
+			// NFTs awaiting a lazy burn are detached and left out of the indexes (v9)
+			if LazyBurns::<T>::contains_key(nft_key) {
+				continue
+			}
+
+			if let Ok((root_owner, _)) = Pallet::<T>::lookup_root_owner(collection_id, nft_id) {
+				// v3
+				ensure!(
+					NftsByRootOwner::<T>::contains_key(&root_owner, nft_key),
+					"nft missing from the index of its root owner"
+				);
+				// v4, v8
+				if nft.pending {
+					ensure!(
+						PendingInbox::<T>::contains_key(
+							&root_owner,
+							PendingItem::Nft { collection_id, nft_id }
+						),
+						"pending nft missing from the inbox of its root owner"
+					);
+				}
+			}
+
+			// v5, v8
+			if nft.pending {
+				let info = PendingNfts::<T>::get(collection_id, nft_id)
+					.ok_or("pending nft without its origin")?;
+				ensure!(
+					PendingNftQueue::<T>::contains_key(info.since, nft_key),
+					"pending nft missing from the timeout queue"
+				);
+			}
+
+			// v6
+			let children = Children::<T>::iter_key_prefix(nft_key).count();
+			ensure!(
+				ChildOrder::<T>::get(nft_key).len() ==
+					children.min(T::MaxChildrenPerNft::get() as usize),
+				"children missing from the child order"
+			);
+
+			// v7, NFTs nested deeper than `MaxRecursions` are not walked
+			let ancestors = Pallet::<T>::self_and_ancestors(nft_key).len().saturating_sub(1);
+			if ancestors < T::MaxRecursions::get() as usize {
+				ensure!(
+					NftDepth::<T>::get(nft_key) as usize == ancestors,
+					"nft depth does not match its ancestors"
+				);
+			}
+
+			// v10, trees too large or deep to walk are not checked
+			if let Ok(subtree) = Pallet::<T>::subtree(nft_key) {
+				ensure!(
+					DescendantCount::<T>::get(nft_key) as usize == subtree.len().saturating_sub(1),
+					"descendant count does not match the tree"
+				);
+			}
+		}
+
+		// v9
+		let queued: Vec<_> = LazyBurnQueue::<T>::iter_values().collect();
+		for nft in LazyBurns::<T>::iter_keys() {
+			ensure!(queued.contains(&nft), "lazy burn missing from the queue");
+		}
+		Ok(())
+	}
+}
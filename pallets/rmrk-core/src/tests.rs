@@ -2,6 +2,7 @@
 // This file is part of rmrk-core.
 // License: Apache 2.0 modified by RMRK, see LICENSE.md
 
+use codec::Encode;
 use frame_support::{assert_noop, assert_ok};
 // use sp_runtime::AccountId32;
 use sp_runtime::Permill;
@@ -45,9 +46,23 @@ macro_rules! bvec {
 	}
 }
 
+/// Shortcut for a royalty paid entirely to a single recipient
+fn royalty(
+	recipient: <Test as frame_system::Config>::AccountId,
+	amount: Permill,
+) -> Option<RoyaltyInfoOf<Test>> {
+	Some(RoyaltyInfo { shares: bvec![(recipient, amount)] })
+}
+
 /// Shortcut for a test collection creation (Alice is issue, max NFTs is 5)
 fn basic_collection() -> DispatchResult {
-	RMRKCore::create_collection(Origin::signed(ALICE), bvec![0u8; 20], Some(5), bvec![0u8; 15])
+	RMRKCore::create_collection(
+		Origin::signed(ALICE),
+		bvec![0u8; 20],
+		Some(5),
+		bvec![0u8; 15],
+		None,
+	)
 }
 
 /// Shortcut for a basic mint (Alice owner, Collection ID 0, Royalty 1.525)
@@ -56,19 +71,91 @@ fn basic_mint() -> DispatchResult {
 		Origin::signed(ALICE),
 		None, // if not specified defaults to minter
 		COLLECTION_ID_0,
-		Some(ALICE),
-		Some(Permill::from_float(1.525)),
+		royalty(ALICE, Permill::from_float(1.525)),
 		bvec![0u8; 20],
 		true,
 		None,
 	)
 }
 
+/// Asserts that every storage item keyed by an NFT or collection belongs to one that still
+/// exists, i.e. that burning NFTs and destroying collections leaves no orphaned keys behind
+fn assert_no_orphaned_keys() {
+	let collection_exists = |collection_id: CollectionId| {
+		assert!(
+			Collections::<Test>::contains_key(collection_id),
+			"orphaned collection {collection_id}"
+		)
+	};
+	let nft_exists = |collection_id: CollectionId, nft_id: NftId| {
+		assert!(
+			Nfts::<Test>::contains_key(collection_id, nft_id),
+			"orphaned nft ({collection_id}, {nft_id})"
+		)
+	};
+
+	// Collection-level items
+	NextNftId::<Test>::iter_keys().for_each(collection_exists);
+	NextCollectionResourceId::<Test>::iter_keys().for_each(collection_exists);
+	CollectionResources::<Test>::iter_keys().for_each(|(cid, _)| collection_exists(cid));
+	PropertyPermissions::<Test>::iter_keys().for_each(|(cid, _)| collection_exists(cid));
+	PropertySchemas::<Test>::iter_keys().for_each(|(cid, _)| collection_exists(cid));
+	CollectionDeposits::<Test>::iter_keys().for_each(collection_exists);
+	CollectionMetadataFrozen::<Test>::iter_keys().for_each(collection_exists);
+	Nfts::<Test>::iter_keys().for_each(|(cid, _)| collection_exists(cid));
+	for (cid, nid, _) in Properties::<Test>::iter_keys() {
+		collection_exists(cid);
+		if let Some(nid) = nid {
+			nft_exists(cid, nid);
+		}
+	}
+
+	// NFT-level items
+	NextResourceId::<Test>::iter_keys().for_each(|(cid, nid)| nft_exists(cid, nid));
+	NftsByRootOwner::<Test>::iter_keys().for_each(|(_, (cid, nid))| nft_exists(cid, nid));
+	for (_, item) in PendingInbox::<Test>::iter_keys() {
+		match item {
+			PendingItem::Nft { collection_id, nft_id } |
+			PendingItem::ResourceAddition { collection_id, nft_id, .. } |
+			PendingItem::ResourceRemoval { collection_id, nft_id, .. } |
+			PendingItem::ResourceReplacement { collection_id, nft_id, .. } =>
+				nft_exists(collection_id, nft_id),
+		}
+	}
+	PendingNfts::<Test>::iter_keys().for_each(|(cid, nid)| nft_exists(cid, nid));
+	PendingNftQueue::<Test>::iter_keys().for_each(|(_, (cid, nid))| nft_exists(cid, nid));
+	Priorities::<Test>::iter_keys().for_each(|(cid, nid, _)| nft_exists(cid, nid));
+	for ((parent_cid, parent_nid), (child_cid, child_nid)) in Children::<Test>::iter_keys() {
+		nft_exists(parent_cid, parent_nid);
+		nft_exists(child_cid, child_nid);
+	}
+	NftDepth::<Test>::iter_keys().for_each(|(cid, nid)| nft_exists(cid, nid));
+	DescendantCount::<Test>::iter_keys().for_each(|(cid, nid)| nft_exists(cid, nid));
+	ChildOrder::<Test>::iter_keys().for_each(|(cid, nid)| nft_exists(cid, nid));
+	LazyBurns::<Test>::iter_keys().for_each(|(cid, nid)| nft_exists(cid, nid));
+	LazyBurnQueue::<Test>::iter_values().for_each(|(cid, nid)| nft_exists(cid, nid));
+	Resources::<Test>::iter_keys().for_each(|(cid, nid, _)| nft_exists(cid, nid));
+	PendingResourceReplacements::<Test>::iter_keys().for_each(|(cid, nid, _)| nft_exists(cid, nid));
+	CollectionResourceOptOuts::<Test>::iter_keys().for_each(|(cid, _, nid)| nft_exists(cid, nid));
+	EquippableBases::<Test>::iter_keys().for_each(|(cid, nid, _)| nft_exists(cid, nid));
+	EquippableSlots::<Test>::iter_keys().for_each(|(cid, nid, ..)| nft_exists(cid, nid));
+	OwnerProperties::<Test>::iter_keys().for_each(|(cid, nid, _)| nft_exists(cid, nid));
+	OwnerPropertyDeposits::<Test>::iter_keys().for_each(|(cid, nid, _)| nft_exists(cid, nid));
+	PropertyDeposits::<Test>::iter_keys().for_each(|(cid, maybe_nid, _)| match maybe_nid {
+		Some(nid) => nft_exists(cid, nid),
+		None => collection_exists(cid),
+	});
+	ChildDeposits::<Test>::iter_keys().for_each(|(cid, nid)| nft_exists(cid, nid));
+	NftMetadataFrozen::<Test>::iter_keys().for_each(|(cid, nid)| nft_exists(cid, nid));
+	Lock::<Test>::iter_keys().for_each(|(cid, nid)| nft_exists(cid, nid));
+	Approvals::<Test>::iter_keys().for_each(|(cid, nid)| nft_exists(cid, nid));
+}
+
 // Tests ordered as follows:
 // Collection: create, lock, destroy, changeissuer
 // NFT: mint, send, burn
 // Resource: create, add, accept
-// Property: set
+// Property: set, remove, permissions, owner properties, schema
 // Priority: set
 
 /// Collection: Basic collection tests (RMRK2.0 spec: CREATE)
@@ -91,6 +178,7 @@ fn create_collection_works() {
 				bvec![0u8; 20],
 				None,
 				bvec![0u8; 15],
+				None,
 			),
 			Error::<Test>::NoAvailableCollectionId
 		);
@@ -106,7 +194,8 @@ fn create_collection_no_max_works() {
 			Origin::signed(ALICE),
 			bvec![0u8; 20],
 			None,
-			bvec![0u8; 15]
+			bvec![0u8; 15],
+			None,
 		));
 		// Creating collection should trigger CollectionCreated event
 		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CollectionCreated {
@@ -215,6 +304,171 @@ fn change_collection_issuer_works() {
 	});
 }
 
+/// Collection: Default royalty is inherited by NFTs minted without a royalty
+#[test]
+fn collection_default_royalty_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let default_royalty = royalty(CHARLIE, Permill::from_percent(10));
+		// Create a collection with a default royalty
+		assert_ok!(RMRKCore::create_collection(
+			Origin::signed(ALICE),
+			bvec![0u8; 20],
+			Some(5),
+			bvec![0u8; 15],
+			default_royalty.clone(),
+		));
+		// Mint an NFT without a royalty
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			None,
+			COLLECTION_ID_0,
+			None,
+			bvec![0u8; 20],
+			true,
+			None
+		));
+		// Mint an NFT with its own royalty
+		assert_ok!(basic_mint());
+		// NFT 0 inherits the collection default, NFT 1 keeps its own royalty
+		assert_eq!(RMRKCore::nft_royalty(COLLECTION_ID_0, 0), default_royalty);
+		assert_eq!(RMRKCore::nft_royalty(COLLECTION_ID_0, 1).unwrap().shares[0].0, ALICE);
+		// BOB is not the issuer and cannot update the default royalty
+		assert_noop!(
+			RMRKCore::set_collection_royalty(Origin::signed(BOB), COLLECTION_ID_0, None),
+			Error::<Test>::NoPermission
+		);
+		// ALICE updates the default royalty
+		let new_royalty = royalty(BOB, Permill::from_percent(5));
+		assert_ok!(RMRKCore::set_collection_royalty(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			new_royalty.clone()
+		));
+		// Setting the default royalty should trigger CollectionRoyaltySet event
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CollectionRoyaltySet {
+			collection_id: COLLECTION_ID_0,
+			royalty: new_royalty.clone(),
+		}));
+		// NFT 0 follows the updated default
+		assert_eq!(RMRKCore::nft_royalty(COLLECTION_ID_0, 0), new_royalty);
+	});
+}
+
+/// Collection: Issuer can update collection metadata until it is frozen
+#[test]
+fn set_collection_metadata_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// BOB is not the issuer and cannot update the metadata
+		assert_noop!(
+			RMRKCore::set_collection_metadata(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				stbd("new-metadata"),
+				false
+			),
+			Error::<Test>::NoPermission
+		);
+		// ALICE updates and freezes the metadata
+		assert_ok!(RMRKCore::set_collection_metadata(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			stbd("new-metadata"),
+			true
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CollectionMetadataSet {
+			collection_id: COLLECTION_ID_0,
+			metadata: stbd("new-metadata"),
+			frozen: true,
+		}));
+		assert_eq!(RMRKCore::collections(COLLECTION_ID_0).unwrap().metadata, stbd("new-metadata"));
+		// Frozen metadata cannot be updated again
+		assert_noop!(
+			RMRKCore::set_collection_metadata(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				stbd("other-metadata"),
+				false
+			),
+			Error::<Test>::MetadataFrozen
+		);
+	});
+}
+
+/// NFT: Issuer can update NFT metadata until it is frozen, unless the NFT is locked
+#[test]
+fn set_nft_metadata_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT
+		assert_ok!(basic_mint());
+		// BOB is not the issuer and cannot update the metadata
+		assert_noop!(
+			RMRKCore::set_nft_metadata(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				stbd("new-metadata"),
+				false
+			),
+			Error::<Test>::NoPermission
+		);
+		// ALICE updates the metadata without freezing it
+		assert_ok!(RMRKCore::set_nft_metadata(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			stbd("new-metadata"),
+			false
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NftMetadataSet {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			metadata: stbd("new-metadata"),
+			frozen: false,
+		}));
+		assert_eq!(
+			RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).unwrap().metadata,
+			stbd("new-metadata")
+		);
+		// Locked NFT metadata cannot be updated
+		RMRKCore::set_lock((COLLECTION_ID_0, NFT_ID_0), true);
+		assert_noop!(
+			RMRKCore::set_nft_metadata(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				stbd("locked-metadata"),
+				false
+			),
+			UNQ::Error::<Test>::Locked
+		);
+		RMRKCore::set_lock((COLLECTION_ID_0, NFT_ID_0), false);
+		// ALICE freezes the metadata
+		assert_ok!(RMRKCore::set_nft_metadata(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			stbd("final-metadata"),
+			true
+		));
+		assert!(RMRKCore::nft_metadata_frozen((COLLECTION_ID_0, NFT_ID_0)));
+		// Frozen metadata cannot be updated again
+		assert_noop!(
+			RMRKCore::set_nft_metadata(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				stbd("other-metadata"),
+				false
+			),
+			Error::<Test>::MetadataFrozen
+		);
+	});
+}
+
 /// NFT: Basic Mint tests (RMRK2.0 spec: MINT)
 #[test]
 fn mint_nft_works() {
@@ -237,8 +491,7 @@ fn mint_nft_works() {
 			Origin::signed(ALICE),
 			None,
 			COLLECTION_ID_0,
-			Some(ALICE),
-			Some(Permill::from_float(20.525)),
+			royalty(ALICE, Permill::from_float(20.525)),
 			bvec![0u8; 20],
 			true,
 			None,
@@ -249,8 +502,7 @@ fn mint_nft_works() {
 				Origin::signed(BOB),
 				Some(BOB),
 				COLLECTION_ID_0,
-				Some(CHARLIE),
-				Some(Permill::from_float(20.525)),
+				royalty(CHARLIE, Permill::from_float(20.525)),
 				bvec![0u8; 20],
 				true,
 				None,
@@ -263,8 +515,7 @@ fn mint_nft_works() {
 				Origin::signed(ALICE),
 				Some(ALICE),
 				NOT_EXISTING_CLASS_ID,
-				Some(CHARLIE),
-				Some(Permill::from_float(20.525)),
+				royalty(CHARLIE, Permill::from_float(20.525)),
 				bvec![0u8; 20],
 				true,
 				None,
@@ -287,8 +538,7 @@ fn mint_directly_to_nft() {
 				Origin::signed(ALICE),
 				(0, 0),
 				COLLECTION_ID_0,
-				None,
-				Some(Permill::from_float(20.525)),
+				royalty(ALICE, Permill::from_float(20.525)),
 				bvec![0u8; 20],
 				true,
 				None,
@@ -301,8 +551,7 @@ fn mint_directly_to_nft() {
 			Origin::signed(ALICE),
 			Some(BOB),
 			COLLECTION_ID_0,
-			None,
-			Some(Permill::from_float(20.525)),
+			royalty(ALICE, Permill::from_float(20.525)),
 			bvec![0u8; 20],
 			true,
 			None,
@@ -319,8 +568,7 @@ fn mint_directly_to_nft() {
 			Origin::signed(ALICE),
 			(0, 0),
 			COLLECTION_ID_0,
-			None,
-			Some(Permill::from_float(20.525)),
+			royalty(ALICE, Permill::from_float(20.525)),
 			bvec![0u8; 20],
 			true,
 			None,
@@ -353,8 +601,7 @@ fn mint_directly_to_nft_with_resources() {
 			Origin::signed(ALICE),
 			Some(BOB),
 			COLLECTION_ID_0,
-			None,
-			Some(Permill::from_float(20.525)),
+			royalty(ALICE, Permill::from_float(20.525)),
 			bvec![0u8; 20],
 			true,
 			None,
@@ -372,8 +619,7 @@ fn mint_directly_to_nft_with_resources() {
 			Origin::signed(ALICE),
 			(0, 0),
 			COLLECTION_ID_0,
-			None,
-			Some(Permill::from_float(20.525)),
+			royalty(ALICE, Permill::from_float(20.525)),
 			bvec![0u8; 20],
 			true,
 			Some(resources_to_add),
@@ -407,64 +653,249 @@ fn mint_collection_max_logic_works() {
 	});
 }
 
-/// NFT: Royalty defaults to self when amount provided but no recipient
+/// Shortcut for a batch mint entry owned by `owner`
+fn batch_entry(owner: Option<<Test as frame_system::Config>::AccountId>) -> MintBatchEntryOf<Test> {
+	MintBatchEntry {
+		owner,
+		royalty: None,
+		metadata: bvec![0u8; 20],
+		transferable: true,
+		resources: None,
+	}
+}
+
+/// NFT: Batch mint tests
 #[test]
-fn royalty_recipient_default_works() {
+fn mint_nft_batch_works() {
 	ExtBuilder::default().build().execute_with(|| {
 		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint an NFT
+		// Empty batch should fail
+		assert_noop!(
+			RMRKCore::mint_nft_batch(Origin::signed(ALICE), COLLECTION_ID_0, bvec![]),
+			Error::<Test>::EmptyMintBatch
+		);
+		// Only the collection issuer can batch mint
+		assert_noop!(
+			RMRKCore::mint_nft_batch(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				bvec![batch_entry(None)]
+			),
+			Error::<Test>::NoPermission
+		);
+		// ALICE mints three NFTs, one of them to BOB
+		assert_ok!(RMRKCore::mint_nft_batch(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			bvec![batch_entry(None), batch_entry(Some(BOB)), batch_entry(None)]
+		));
+		// A single event covers the minted range
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NftBatchMinted {
+			issuer: ALICE,
+			collection_id: COLLECTION_ID_0,
+			first_nft_id: 0,
+			last_nft_id: 2,
+		}));
+		assert_eq!(RMRKCore::collections(COLLECTION_ID_0).unwrap().nfts_count, 3);
+		// NFT minted to BOB is pending
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 1).unwrap().pending, true);
+		assert_eq!(UNQ::Pallet::<Test>::owner(COLLECTION_ID_0, 1), Some(BOB));
+		// Batch exceeding the collection max (5) should fail without minting anything
+		assert_noop!(
+			RMRKCore::mint_nft_batch(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				bvec![batch_entry(None), batch_entry(None), batch_entry(None)]
+			),
+			Error::<Test>::CollectionFullOrLocked
+		);
+		assert_eq!(RMRKCore::collections(COLLECTION_ID_0).unwrap().nfts_count, 3);
+	});
+}
+
+/// NFT: Royalty is shared between multiple recipients, up to 100% in total
+#[test]
+fn royalty_shares_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		let shares: RoyaltyInfoOf<Test> = RoyaltyInfo {
+			shares: bvec![(ALICE, Permill::from_percent(10)), (BOB, Permill::from_percent(20))],
+		};
+		// Mint an NFT with royalty shared between ALICE and BOB
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(ALICE),
 			COLLECTION_ID_0,
-			None, // No royalty recipient
-			Some(Permill::from_float(20.525)),
+			Some(shares.clone()),
 			bvec![0u8; 20],
 			true,
 			None,
 		));
-		// Royalty recipient should default to issuer (ALICE)
-		assert_eq!(RmrkCore::nfts(0, 0).unwrap().royalty.unwrap().recipient, ALICE);
-		// Mint another NFT
+		// Both shares are stored
+		assert_eq!(RmrkCore::nfts(0, 0).unwrap().royalty, Some(shares));
+		// Minting with shares adding up to more than 100% fails
+		assert_noop!(
+			RMRKCore::mint_nft(
+				Origin::signed(ALICE),
+				Some(ALICE),
+				COLLECTION_ID_0,
+				Some(RoyaltyInfo {
+					shares: bvec![
+						(ALICE, Permill::from_percent(60)),
+						(BOB, Permill::from_percent(50))
+					],
+				}),
+				bvec![0u8; 20],
+				true,
+				None,
+			),
+			Error::<Test>::RoyaltyExceedsTotal
+		);
+		// Mint another NFT without royalty
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(ALICE),
 			COLLECTION_ID_0,
-			Some(BOB), // Royalty recipient is BOB
-			Some(Permill::from_float(20.525)),
+			None,
+			bvec![0u8; 20],
+			true,
+			None,
+		));
+		// Royalty should not exist
+		assert!(RmrkCore::nfts(0, 1).unwrap().royalty.is_none());
+	});
+}
+
+/// NFT: Royalty defaults to a single share to the minter when no recipient is provided
+#[test]
+fn royalty_recipient_default_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint an NFT
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(BOB),
+			COLLECTION_ID_0,
+			Some(RoyaltyInfo { shares: bvec![] }), // No royalty recipient
 			bvec![0u8; 20],
 			true,
 			None,
 		));
-		// Royalty recipient should be BOB
-		assert_eq!(RmrkCore::nfts(0, 1).unwrap().royalty.unwrap().recipient, BOB);
+		// Royalty should default to a single 100% share to the minter (ALICE)
+		assert_eq!(RmrkCore::nfts(0, 0).unwrap().royalty, royalty(ALICE, Permill::one()));
 		// Mint another NFT
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(ALICE),
 			COLLECTION_ID_0,
-			None, // No royalty recipient is BOB
-			None, // No royalty amount
+			royalty(BOB, Permill::from_float(20.525)), // Royalty recipient is BOB
 			bvec![0u8; 20],
 			true,
 			None,
 		));
-		// Royalty should not exist
-		assert!(RmrkCore::nfts(0, 2).unwrap().royalty.is_none());
+		// Royalty recipient should be BOB
+		assert_eq!(RmrkCore::nfts(0, 1).unwrap().royalty.unwrap().shares[0].0, BOB);
 		// Mint another NFT
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(ALICE),
 			COLLECTION_ID_0,
-			Some(ALICE), // Royalty recipient is ALICE
-			None,        // No royalty amount
+			None, // No royalty
 			bvec![0u8; 20],
 			true,
 			None,
 		));
 		// Royalty should not exist
-		assert!(RmrkCore::nfts(0, 3).unwrap().royalty.is_none());
+		assert!(RmrkCore::nfts(0, 2).unwrap().royalty.is_none());
+	});
+}
+
+/// Migration: Single recipient royalties are migrated to royalty shares
+#[test]
+fn royalty_migration_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		use crate::migration::v1::*;
+		use frame_support::{
+			storage::unhashed,
+			traits::{GetStorageVersion, StorageVersion},
+		};
+		StorageVersion::new(0).put::<RMRKCore>();
+		// Store a collection and an NFT in their pre-v1 format
+		unhashed::put(
+			&Collections::<Test>::hashed_key_for(COLLECTION_ID_0),
+			&OldCollectionInfoOf::<Test> {
+				issuer: ALICE,
+				metadata: bvec![0u8; 20],
+				max: None,
+				symbol: bvec![0u8; 15],
+				nfts_count: 1,
+			},
+		);
+		unhashed::put(
+			&Nfts::<Test>::hashed_key_for(COLLECTION_ID_0, NFT_ID_0),
+			&OldNftInfoOf::<Test> {
+				owner: AccountIdOrCollectionNftTuple::AccountId(ALICE),
+				royalty: Some(OldRoyaltyInfo { recipient: BOB, amount: Permill::from_percent(10) }),
+				metadata: bvec![0u8; 20],
+				equipped: false,
+				pending: false,
+				transferable: true,
+			},
+		);
+		// Run the migration
+		migrate::<Test>();
+		// Collection has no default royalty, NFT royalty becomes a single share
+		assert_eq!(RMRKCore::collections(COLLECTION_ID_0).unwrap().royalty, None);
+		assert_eq!(
+			RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).unwrap().royalty,
+			royalty(BOB, Permill::from_percent(10))
+		);
+		assert_eq!(RMRKCore::on_chain_storage_version(), StorageVersion::new(1));
+	});
+}
+
+/// Migration: Equippable bases are turned into resource counts
+#[test]
+fn equippable_bases_migration_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		use frame_support::{
+			storage::unhashed,
+			traits::{GetStorageVersion, StorageVersion},
+		};
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT with two composable resources using base 0
+		assert_ok!(basic_mint());
+		for _ in 0..2 {
+			assert_ok!(RMRKCore::add_composable_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				ComposableResource {
+					parts: bvec![0],
+					src: None,
+					base: 0,
+					license: None,
+					metadata: None,
+					slot: None,
+					thumb: None,
+				}
+			));
+		}
+		// Store the bases in their pre-v2 format, including a base left behind by a removed
+		// resource
+		StorageVersion::new(1).put::<RMRKCore>();
+		unhashed::put(&EquippableBases::<Test>::hashed_key_for((0, 0, 0)), &());
+		unhashed::put(&EquippableBases::<Test>::hashed_key_for((0, 0, 7)), &());
+		// Run the migration
+		crate::migration::v2::migrate::<Test>();
+		// Bases count the resources using them, unused bases are dropped
+		assert_eq!(RMRKCore::equippable_bases((0, 0, 0)), Some(2));
+		assert_eq!(RMRKCore::equippable_bases((0, 0, 7)), None);
+		assert_eq!(RMRKCore::on_chain_storage_version(), StorageVersion::new(2));
 	});
 }
 
@@ -629,8 +1060,7 @@ fn send_non_transferable_fail() {
 			Origin::signed(ALICE),
 			Some(ALICE),
 			COLLECTION_ID_0,
-			Some(ALICE),
-			Some(Permill::from_float(1.525)),
+			royalty(ALICE, Permill::from_float(1.525)),
 			bvec![0u8; 20],
 			false, // non-transferable
 			None,
@@ -658,8 +1088,7 @@ fn mint_non_transferrable_gem_on_to_nft_works() {
 			Origin::signed(ALICE),
 			Some(BOB),
 			COLLECTION_ID_0,
-			Some(ALICE),
-			Some(Permill::from_float(1.525)),
+			royalty(ALICE, Permill::from_float(1.525)),
 			bvec![0u8; 20],
 			true, // transferable
 			None,
@@ -670,8 +1099,7 @@ fn mint_non_transferrable_gem_on_to_nft_works() {
 			Origin::signed(ALICE),
 			(0, 0),
 			COLLECTION_ID_0,
-			Some(ALICE),
-			Some(Permill::from_float(1.525)),
+			royalty(ALICE, Permill::from_float(1.525)),
 			bvec![0u8; 20],
 			false, // non-transferable
 			None,
@@ -729,7 +1157,7 @@ fn reject_nft_works() {
 		assert_ok!(basic_collection());
 		// Cannot reject non-existent NFT
 		assert_noop!(
-			RMRKCore::reject_nft(Origin::signed(BOB), 0, 2,),
+			RMRKCore::reject_nft(Origin::signed(BOB), 0, 2, false),
 			Error::<Test>::NoAvailableNftId
 		);
 		// Mint NFTs (0, 0), (0, 1), (0, 2)
@@ -757,8 +1185,15 @@ fn reject_nft_works() {
 			0,
 			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
 		));
-		// Bob rejects NFT (0,0) for Bob-owned NFT (0,0)
-		assert_ok!(RMRKCore::reject_nft(Origin::signed(BOB), 0, 0,));
+		// Bob rejects NFT (0,0) for Bob-owned NFT (0,0), opting to burn it
+		assert_ok!(RMRKCore::reject_nft(Origin::signed(BOB), 0, 0, true));
+		// Rejection triggers NFTRejected event
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTRejected {
+			sender: BOB,
+			collection_id: 0,
+			nft_id: 0,
+			returned_to: None,
+		}));
 		// Rejected NFT gets burned
 		assert!(RMRKCore::nfts(0, 0).is_none());
 		// Child is burned if parent is rejected
@@ -777,8 +1212,7 @@ fn reject_cannot_reject_non_pending_nft() {
 			Origin::signed(ALICE),
 			None,
 			COLLECTION_ID_0,
-			Some(ALICE),
-			Some(Permill::from_float(1.525)),
+			royalty(ALICE, Permill::from_float(1.525)),
 			bvec![0u8; 20],
 			true,
 			None
@@ -787,7 +1221,7 @@ fn reject_cannot_reject_non_pending_nft() {
 		assert!(!RMRKCore::nfts(0, 0).unwrap().pending);
 		// ALICE cannot reject NFT (0, 0) since it is not pending
 		assert_noop!(
-			RMRKCore::reject_nft(Origin::signed(ALICE), 0, 0),
+			RMRKCore::reject_nft(Origin::signed(ALICE), 0, 0, false),
 			Error::<Test>::CannotRejectNonPendingNft
 		);
 		// NFT (0, 0) still exists after failed rejection
@@ -795,6 +1229,48 @@ fn reject_cannot_reject_non_pending_nft() {
 	});
 }
 
+/// NFT: A pending NFT sent on by its recipient is no longer pending
+#[test]
+fn send_pending_nft_clears_pending() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// ALICE mints (0, 0) for ALICE and (0, 1) for BOB
+		assert_ok!(basic_mint());
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(BOB),
+			COLLECTION_ID_0,
+			None,
+			bvec![0u8; 20],
+			true,
+			None
+		));
+		// BOB sends NFT (0, 1) to ALICE's NFT (0, 0), where it is pending
+		assert_ok!(RMRKCore::send(
+			Origin::signed(BOB),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+		));
+		assert!(RMRKCore::nfts(0, 1).unwrap().pending);
+		// ALICE sends NFT (0, 1) on to CHARLIE, which needs no acceptance
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::AccountId(CHARLIE),
+		));
+		// NFT (0, 1) is neither flagged nor recorded as pending anymore
+		assert!(!RMRKCore::nfts(0, 1).unwrap().pending);
+		assert!(RMRKCore::pending_nfts(0, 1).is_none());
+		assert_noop!(
+			RMRKCore::reject_nft(Origin::signed(CHARLIE), 0, 1, false),
+			Error::<Test>::CannotRejectNonPendingNft
+		);
+	});
+}
+
 /// NFT: Reject tests (RMRK2.0 spec: new)
 #[test]
 fn reject_nft_removes_self_from_parents_children() {
@@ -808,8 +1284,7 @@ fn reject_nft_removes_self_from_parents_children() {
 			Origin::signed(ALICE),
 			Some(BOB),
 			COLLECTION_ID_0,
-			Some(ALICE),
-			Some(Permill::from_float(1.525)),
+			royalty(ALICE, Permill::from_float(1.525)),
 			bvec![0u8; 20],
 			true,
 			None
@@ -822,16 +1297,16 @@ fn reject_nft_removes_self_from_parents_children() {
 			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
 		));
 		// ALICE rejects NFT (0, 1)
-		assert_ok!(RMRKCore::reject_nft(Origin::signed(ALICE), 0, 1));
-		// Rejected NFT gets burned
-		assert_eq!(RMRKCore::nfts(0, 1).is_none(), true);
+		assert_ok!(RMRKCore::reject_nft(Origin::signed(ALICE), 0, 1, false));
+		// Rejected NFT is returned to BOB
+		assert_eq!(RMRKCore::lookup_root_owner(0, 1).unwrap().0, BOB);
 		assert_eq!(RMRKCore::children((0, 0), (0, 1)).is_none(), true);
 	});
 }
 
-/// NFT: Send tests, siblings (RMRK2.0 spec: SEND)
+/// NFT: Rejecting returns the NFT and its descendants to the NFT that sent it
 #[test]
-fn send_two_nfts_to_same_nft_creates_two_children() {
+fn reject_nft_returns_to_previous_parent() {
 	ExtBuilder::default().build().execute_with(|| {
 		// Create a basic collection
 		assert_ok!(basic_collection());
@@ -846,685 +1321,3311 @@ fn send_two_nfts_to_same_nft_creates_two_children() {
 			1,
 			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
 		));
-		// NFT (0,0) has NFT (0,1) in Children StorageMap
-		assert!(RMRKCore::children((0, 0), (0, 1)).is_some());
-		// ALICE sends NFT (0, 2) to NFT (0, 0)
+		// ALICE sends NFT (0, 2) to BOB
 		assert_ok!(RMRKCore::send(
 			Origin::signed(ALICE),
 			0,
 			2,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
 		));
-		// NFT (0,0) has NFT (0,1) & (0,2) in Children StorageMap
+		// ALICE sends NFT (0, 1) from NFT (0, 0) to BOB-owned NFT (0, 2)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+		));
+		// NFT (0, 1) is pending, and its origin is recorded
+		assert!(RMRKCore::nfts(0, 1).unwrap().pending);
+		assert_eq!(
+			RMRKCore::pending_nfts(0, 1).unwrap().previous_owner,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0)
+		);
+		// ALICE cannot reject the NFT she sent
+		assert_noop!(
+			RMRKCore::reject_nft(Origin::signed(ALICE), 0, 1, false),
+			Error::<Test>::CannotRejectNonOwnedNft
+		);
+		// BOB rejects NFT (0, 1)
+		assert_ok!(RMRKCore::reject_nft(Origin::signed(BOB), 0, 1, false));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTRejected {
+			sender: BOB,
+			collection_id: 0,
+			nft_id: 1,
+			returned_to: Some(AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0)),
+		}));
+		// NFT (0, 1) still exists, is no longer pending and is back under NFT (0, 0)
+		let nft = RMRKCore::nfts(0, 1).unwrap();
+		assert!(!nft.pending);
+		assert_eq!(nft.owner, AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0));
+		assert_eq!(UNQ::Pallet::<Test>::owner(0, 1), Some(RMRKCore::nft_to_account_id(0, 0)));
 		assert!(RMRKCore::children((0, 0), (0, 1)).is_some());
-		assert!(RMRKCore::children((0, 0), (0, 2)).is_some());
+		assert!(RMRKCore::children((0, 2), (0, 1)).is_none());
+		assert!(RMRKCore::pending_nfts(0, 1).is_none());
+		// ALICE root-owns NFT (0, 1) again
+		assert_eq!(RMRKCore::lookup_root_owner(0, 1).unwrap().0, ALICE);
+		// NFT (0, 1) is no longer pending, so it cannot be rejected again
+		assert_noop!(
+			RMRKCore::reject_nft(Origin::signed(ALICE), 0, 1, false),
+			Error::<Test>::CannotRejectNonPendingNft
+		);
 	});
 }
 
-/// NFT: Send tests, removing parent (RMRK2.0 spec: SEND)
+/// NFT: Accepting pending NFTs in bulk
 #[test]
-fn send_nft_removes_existing_parent() {
+fn accept_nfts_in_bulk_works() {
 	ExtBuilder::default().build().execute_with(|| {
 		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint NFTs (0, 0), (0, 1), (0, 2), (0, 3)
-		for _ in 0..4 {
+		// Mint NFT (0, 0) to BOB, who accepts it
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(BOB),
+			COLLECTION_ID_0,
+			None,
+			bvec![0u8; 20],
+			true,
+			None
+		));
+		assert_ok!(RMRKCore::accept_nfts(Origin::signed(BOB), bvec![(0, 0)]));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTAccepted {
+			sender: BOB,
+			recipient: AccountIdOrCollectionNftTuple::AccountId(BOB),
+			collection_id: 0,
+			nft_id: 0,
+		}));
+		// ALICE sends NFTs (0, 1) and (0, 2) to BOB's NFT (0, 0) and mints NFT (0, 3) into it
+		for nft_id in 1..=2 {
 			assert_ok!(basic_mint());
+			assert_ok!(RMRKCore::send(
+				Origin::signed(ALICE),
+				0,
+				nft_id,
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			));
 		}
-		// ALICE sends NFT (0, 1) to NFT (0, 0)
+		assert_ok!(RMRKCore::mint_nft_directly_to_nft(
+			Origin::signed(ALICE),
+			(0, 0),
+			COLLECTION_ID_0,
+			None,
+			bvec![0u8; 20],
+			true,
+			None
+		));
+		assert!(RMRKCore::nfts(0, 3).unwrap().pending);
+		// ALICE cannot accept children of BOB's NFT
+		assert_noop!(
+			RMRKCore::accept_all_pending(Origin::signed(ALICE), 0, 0),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			RMRKCore::accept_nfts(Origin::signed(ALICE), bvec![(0, 1)]),
+			Error::<Test>::NoPermission
+		);
+		// BOB accepts NFT (0, 1) under NFT (0, 0) without naming the parent
+		assert_ok!(RMRKCore::accept_nfts(Origin::signed(BOB), bvec![(0, 1)]));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTAccepted {
+			sender: BOB,
+			recipient: AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			collection_id: 0,
+			nft_id: 1,
+		}));
+		assert!(!RMRKCore::nfts(0, 1).unwrap().pending);
+		// An NFT that is no longer pending cannot be accepted again
+		assert_noop!(
+			RMRKCore::accept_nfts(Origin::signed(BOB), bvec![(0, 1)]),
+			Error::<Test>::CannotAcceptNonPendingNft
+		);
+		// BOB accepts the remaining pending children of NFT (0, 0)
+		assert_ok!(RMRKCore::accept_all_pending(Origin::signed(BOB), 0, 0));
+		assert!(!RMRKCore::nfts(0, 2).unwrap().pending);
+		assert!(!RMRKCore::nfts(0, 3).unwrap().pending);
+		assert!(RMRKCore::pending_nfts(0, 2).is_none());
+		assert!(RMRKCore::pending_nfts(0, 3).is_none());
+		assert!(RMRKCore::pending_inbox_of(&BOB, 0, u32::MAX).is_empty());
+		// All children stay under NFT (0, 0)
+		for nft_id in 1..=3 {
+			assert_eq!(
+				UNQ::Pallet::<Test>::owner(0, nft_id),
+				Some(RMRKCore::nft_to_account_id(0, 0))
+			);
+		}
+	});
+}
+
+/// NFT: Incoming policies refuse, block or auto-accept NFTs sent under another account's NFTs
+#[test]
+fn incoming_policy_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// ALICE creates collection 0 and BOB creates collection 1
+		assert_ok!(basic_collection());
+		assert_ok!(RMRKCore::create_collection(
+			Origin::signed(BOB),
+			bvec![0u8; 20],
+			Some(5),
+			bvec![0u8; 15],
+			None,
+		));
+		// BOB mints NFT (1, 0)
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(BOB),
+			None,
+			1,
+			None,
+			bvec![0u8; 20],
+			true,
+			None
+		));
+		// BOB refuses every incoming NFT
+		let policy: IncomingPolicyOf<Test> = IncomingPolicy {
+			refuse_all: true,
+			blocked_collections: bvec![],
+			auto_accept_collections: bvec![],
+			auto_accept_senders: bvec![],
+		};
+		assert_ok!(RMRKCore::set_incoming_policy(Origin::signed(BOB), Some(policy.clone())));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::IncomingPolicySet {
+			account: BOB,
+			policy: Some(policy.clone()),
+		}));
+		// ALICE can neither send nor mint NFTs under NFT (1, 0)
+		assert_ok!(basic_mint());
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(ALICE),
+				0,
+				0,
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(1, 0),
+			),
+			Error::<Test>::IncomingNftRefused
+		);
+		assert_noop!(
+			RMRKCore::mint_nft_directly_to_nft(
+				Origin::signed(ALICE),
+				(1, 0),
+				COLLECTION_ID_0,
+				None,
+				bvec![0u8; 20],
+				true,
+				None
+			),
+			Error::<Test>::IncomingNftRefused
+		);
+		// BOB can still mint under NFT (1, 0)
+		assert_ok!(RMRKCore::mint_nft_directly_to_nft(
+			Origin::signed(BOB),
+			(1, 0),
+			1,
+			None,
+			bvec![0u8; 20],
+			true,
+			None
+		));
+		// BOB auto-accepts NFTs sent by ALICE
+		let policy = IncomingPolicy { auto_accept_senders: bvec![ALICE], ..policy };
+		assert_ok!(RMRKCore::set_incoming_policy(Origin::signed(BOB), Some(policy.clone())));
+		// ALICE's NFT (0, 0) is added under NFT (1, 0) without being pending
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			0,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(1, 0),
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTSent {
+			sender: ALICE,
+			recipient: AccountIdOrCollectionNftTuple::CollectionAndNftTuple(1, 0),
+			collection_id: 0,
+			nft_id: 0,
+			approval_required: false,
+		}));
+		assert!(!RMRKCore::nfts(0, 0).unwrap().pending);
+		assert!(RMRKCore::pending_nfts(0, 0).is_none());
+		assert!(RMRKCore::children((1, 0), (0, 0)).is_some());
+		// Blocking collection 0 takes precedence over auto-accepting ALICE
+		let policy = IncomingPolicy { blocked_collections: bvec![0], ..policy };
+		assert_ok!(RMRKCore::set_incoming_policy(Origin::signed(BOB), Some(policy)));
+		assert_ok!(basic_mint());
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(ALICE),
+				0,
+				1,
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(1, 0),
+			),
+			Error::<Test>::IncomingNftBlocked
+		);
+		// BOB auto-accepts NFTs of collection 0 only
+		let policy = IncomingPolicy {
+			refuse_all: false,
+			blocked_collections: bvec![],
+			auto_accept_collections: bvec![0],
+			auto_accept_senders: bvec![],
+		};
+		assert_ok!(RMRKCore::set_incoming_policy(Origin::signed(BOB), Some(policy)));
+		// ALICE mints NFT (0, 2) directly under NFT (1, 0) without it being pending
+		assert_ok!(RMRKCore::mint_nft_directly_to_nft(
+			Origin::signed(ALICE),
+			(1, 0),
+			COLLECTION_ID_0,
+			None,
+			bvec![0u8; 20],
+			true,
+			None
+		));
+		assert!(!RMRKCore::nfts(0, 2).unwrap().pending);
+		// BOB clears the policy, after which incoming NFTs are pending again
+		assert_ok!(RMRKCore::set_incoming_policy(Origin::signed(BOB), None));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::IncomingPolicySet {
+			account: BOB,
+			policy: None,
+		}));
+		assert!(RMRKCore::incoming_policies(BOB).is_none());
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(1, 0),
+		));
+		assert!(RMRKCore::nfts(0, 1).unwrap().pending);
+	});
+}
+
+/// NFT: Children are limited per parent and listed in an order the root owner can change
+#[test]
+fn child_limit_and_order_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a collection without a max
+		assert_ok!(RMRKCore::create_collection(
+			Origin::signed(ALICE),
+			bvec![0u8; 20],
+			None,
+			bvec![0u8; 15],
+			None,
+		));
+		// Mint NFTs (0, 0) to (0, 6)
+		for _ in 0..7 {
+			assert_ok!(basic_mint());
+		}
+		// ALICE sends NFTs (0, 1) to (0, 5) to NFT (0, 0), filling it up
+		for nft_id in 1..=5 {
+			assert_ok!(RMRKCore::send(
+				Origin::signed(ALICE),
+				0,
+				nft_id,
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			));
+		}
+		assert_eq!(
+			RMRKCore::child_order((0, 0)).into_inner(),
+			vec![(0, 1), (0, 2), (0, 3), (0, 4), (0, 5)]
+		);
+		// NFT (0, 0) cannot get a sixth child, whether sent or minted
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(ALICE),
+				0,
+				6,
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			),
+			Error::<Test>::TooManyChildren
+		);
+		assert_noop!(
+			RMRKCore::mint_nft_directly_to_nft(
+				Origin::signed(ALICE),
+				(0, 0),
+				COLLECTION_ID_0,
+				None,
+				bvec![0u8; 20],
+				true,
+				None
+			),
+			Error::<Test>::TooManyChildren
+		);
+		// Only the root owner can reorder the children
+		assert_noop!(
+			RMRKCore::reorder_children(
+				Origin::signed(BOB),
+				0,
+				0,
+				bvec![(0, 5), (0, 4), (0, 3), (0, 2), (0, 1)]
+			),
+			Error::<Test>::NoPermission
+		);
+		// The new order must list every child exactly once
+		assert_noop!(
+			RMRKCore::reorder_children(
+				Origin::signed(ALICE),
+				0,
+				0,
+				bvec![(0, 5), (0, 4), (0, 3), (0, 2)]
+			),
+			Error::<Test>::InvalidChildOrder
+		);
+		assert_noop!(
+			RMRKCore::reorder_children(
+				Origin::signed(ALICE),
+				0,
+				0,
+				bvec![(0, 5), (0, 4), (0, 3), (0, 2), (0, 2)]
+			),
+			Error::<Test>::InvalidChildOrder
+		);
+		// ALICE reverses the children of NFT (0, 0)
+		assert_ok!(RMRKCore::reorder_children(
+			Origin::signed(ALICE),
+			0,
+			0,
+			bvec![(0, 5), (0, 4), (0, 3), (0, 2), (0, 1)]
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ChildrenReordered {
+			collection_id: 0,
+			nft_id: 0,
+		}));
+		assert_eq!(
+			RMRKCore::child_order((0, 0)).into_inner(),
+			vec![(0, 5), (0, 4), (0, 3), (0, 2), (0, 1)]
+		);
+		// Sending NFT (0, 3) away keeps the order of the remaining children and makes room for
+		// NFT (0, 6), which is listed last
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			3,
+			AccountIdOrCollectionNftTuple::AccountId(ALICE),
+		));
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			6,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+		));
+		assert_eq!(
+			RMRKCore::child_order((0, 0)).into_inner(),
+			vec![(0, 5), (0, 4), (0, 2), (0, 1), (0, 6)]
+		);
+		// Burning NFT (0, 0) removes its child order
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, MAX_BURNS));
+		assert!(RMRKCore::child_order((0, 0)).is_empty());
+	});
+}
+
+/// NFT: Nesting deeper than MaxRecursions is rejected up front, depths follow moved trees
+#[test]
+fn nesting_depth_is_bounded() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a collection without a max
+		assert_ok!(RMRKCore::create_collection(
+			Origin::signed(ALICE),
+			bvec![0u8; 20],
+			None,
+			bvec![0u8; 15],
+			None,
+		));
+		// Mint NFTs (0, 0) to (0, 6)
+		for _ in 0..7 {
+			assert_ok!(basic_mint());
+		}
+		// ALICE nests NFTs (0, 1) to (0, 4) in a chain under NFT (0, 0)
+		for nft_id in 1..=4 {
+			assert_ok!(RMRKCore::send(
+				Origin::signed(ALICE),
+				0,
+				nft_id,
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, nft_id - 1),
+			));
+			assert_eq!(RMRKCore::nft_depth((0, nft_id)), nft_id);
+		}
+		assert_eq!(RMRKCore::nft_depth((0, 0)), 0);
+		assert_eq!(RMRKCore::lookup_root_owner(0, 4).unwrap(), (ALICE, (0, 0)));
+		assert!(RMRKCore::is_x_descendent_of_y(0, 4, 0, 1).unwrap());
+		assert!(!RMRKCore::is_x_descendent_of_y(0, 1, 0, 4).unwrap());
+		// NFT (0, 4) is at depth MaxRecursions and cannot get any child
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(ALICE),
+				0,
+				5,
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 4),
+			),
+			Error::<Test>::TooManyRecursions
+		);
+		assert_noop!(
+			RMRKCore::mint_nft_directly_to_nft(
+				Origin::signed(ALICE),
+				(0, 4),
+				COLLECTION_ID_0,
+				None,
+				bvec![0u8; 20],
+				true,
+				None
+			),
+			Error::<Test>::TooManyRecursions
+		);
+		// ALICE sends NFT (0, 5) to NFT (0, 6)
 		assert_ok!(RMRKCore::send(
 			Origin::signed(ALICE),
 			0,
+			5,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 6),
+		));
+		// NFT (0, 6) fits under NFT (0, 3), but its child NFT (0, 5) would be too deep
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(ALICE),
+				0,
+				6,
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 3),
+			),
+			Error::<Test>::TooManyRecursions
+		);
+		// Under NFT (0, 2), the depths of NFT (0, 6) and its child are updated
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			6,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+		));
+		assert_eq!(RMRKCore::nft_depth((0, 6)), 3);
+		assert_eq!(RMRKCore::nft_depth((0, 5)), 4);
+		// Sending NFT (0, 1) back to ALICE moves its whole tree up
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::AccountId(ALICE),
+		));
+		assert_eq!(RMRKCore::nft_depth((0, 1)), 0);
+		assert_eq!(RMRKCore::nft_depth((0, 2)), 1);
+		assert_eq!(RMRKCore::nft_depth((0, 4)), 3);
+		assert_eq!(RMRKCore::nft_depth((0, 5)), 3);
+		// Burning NFT (0, 1) removes the depths of its tree
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, 1, MAX_BURNS));
+		assert_eq!(NftDepth::<Test>::iter().count(), 0);
+	});
+}
+
+/// NFT: Trees of NFTs are bounded by MaxDescendants, descendant counts follow moved trees
+#[test]
+fn descendant_count_is_bounded() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a collection without a max
+		assert_ok!(RMRKCore::create_collection(
+			Origin::signed(ALICE),
+			bvec![0u8; 20],
+			None,
+			bvec![0u8; 15],
+			None,
+		));
+		// Mint NFTs (0, 0) to (0, 9)
+		for _ in 0..10 {
+			assert_ok!(basic_mint());
+		}
+		// ALICE builds the tree (0, 0) -> [(0, 1) -> [(0, 6), (0, 7), (0, 8)], (0, 2) .. (0, 5)]
+		for (nft_id, parent) in [(6, 1), (7, 1), (8, 1), (1, 0), (2, 0), (3, 0), (4, 0), (5, 0)] {
+			assert_ok!(RMRKCore::send(
+				Origin::signed(ALICE),
+				0,
+				nft_id,
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, parent),
+			));
+		}
+		assert_eq!(RMRKCore::descendant_count((0, 1)), 3);
+		assert_eq!(RMRKCore::descendant_count((0, 0)), MaxDescendants::get());
+		// NFT (0, 0) has MaxDescendants descendants, so no NFT can be nested anywhere below it
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(ALICE),
+				0,
+				9,
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+			),
+			Error::<Test>::TooManyDescendants
+		);
+		assert_noop!(
+			RMRKCore::mint_nft_directly_to_nft(
+				Origin::signed(ALICE),
+				(0, 1),
+				COLLECTION_ID_0,
+				None,
+				bvec![0u8; 20],
+				true,
+				None
+			),
+			Error::<Test>::TooManyDescendants
+		);
+		// Sending NFT (0, 1) back to ALICE takes its descendants along
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::AccountId(ALICE),
+		));
+		assert_eq!(RMRKCore::descendant_count((0, 0)), 4);
+		assert_eq!(RMRKCore::descendant_count((0, 1)), 3);
+		// NFT (0, 9) now fits below NFT (0, 2), counting for both of its ancestors
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			9,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+		));
+		assert_eq!(RMRKCore::descendant_count((0, 2)), 1);
+		assert_eq!(RMRKCore::descendant_count((0, 0)), 5);
+		// Burning NFT (0, 2) removes it and NFT (0, 9) from the count of NFT (0, 0)
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, 2, MAX_BURNS));
+		assert_eq!(RMRKCore::descendant_count((0, 0)), 3);
+		assert_no_orphaned_keys();
+	});
+}
+
+/// NFT: Walks over a tree fail before any change when it is too deep or too large
+#[test]
+fn subtree_walks_are_bounded() {
+	ExtBuilder::default().build().execute_with(|| {
+		// A chain of NFTs (0, 0) -> (0, 1) -> ... -> (0, 6), deeper than MaxRecursions
+		for nft_id in 0..6 {
+			Children::<Test>::insert((0, nft_id), (0, nft_id + 1), ());
+		}
+		assert_eq!(RMRKCore::subtree((0, 1)), Err(Error::<Test>::TooManyRecursions.into()));
+		assert_eq!(
+			RMRKCore::subtree((0, 2)).unwrap(),
+			vec![((0, 2), 0), ((0, 3), 1), ((0, 4), 2), ((0, 5), 3), ((0, 6), 4)]
+		);
+		// NFT (0, 10) has more than MaxDescendants children
+		for nft_id in 11..20 {
+			Children::<Test>::insert((0, 10), (0, nft_id), ());
+		}
+		assert_eq!(RMRKCore::subtree((0, 10)), Err(Error::<Test>::TooManyDescendants.into()));
+		// Neither tree can move to another root owner
+		assert_noop!(
+			RMRKCore::move_root_owner_index(0, 0, &ALICE, &BOB),
+			Error::<Test>::TooManyRecursions
+		);
+		assert_noop!(
+			RMRKCore::move_root_owner_index(0, 10, &ALICE, &BOB),
+			Error::<Test>::TooManyDescendants
+		);
+	});
+}
+
+/// Migration: Descendant counts are populated from the existing trees
+#[test]
+fn descendant_count_migration_works() {
+	use frame_support::traits::{GetStorageVersion, StorageVersion};
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(basic_collection());
+		for _ in 0..3 {
+			assert_ok!(basic_mint());
+		}
+		// ALICE builds the chain (0, 0) -> (0, 1) -> (0, 2)
+		for nft_id in 1..=2 {
+			assert_ok!(RMRKCore::send(
+				Origin::signed(ALICE),
+				0,
+				nft_id,
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, nft_id - 1),
+			));
+		}
+		// The counts did not exist before v10
+		DescendantCount::<Test>::remove_all(None);
+		StorageVersion::new(9).put::<RMRKCore>();
+		crate::migration::v10::migrate::<Test>();
+		assert_eq!(RMRKCore::descendant_count((0, 0)), 2);
+		assert_eq!(RMRKCore::descendant_count((0, 1)), 1);
+		assert_eq!(RMRKCore::descendant_count((0, 2)), 0);
+		assert_eq!(RMRKCore::on_chain_storage_version(), StorageVersion::new(10));
+	});
+}
+
+/// NFT: Lazy burn removes the NFT at once and its descendants over the following blocks
+#[test]
+fn burn_nft_lazy_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFTs (0, 0) to (0, 4)
+		for _ in 0..5 {
+			assert_ok!(basic_mint());
+		}
+		// ALICE builds the tree (0, 0) -> [(0, 1) -> (0, 3) -> (0, 4), (0, 2)]
+		for (nft_id, parent) in [(1, 0), (2, 0), (3, 1), (4, 3)] {
+			assert_ok!(RMRKCore::send(
+				Origin::signed(ALICE),
+				0,
+				nft_id,
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, parent),
+			));
+		}
+		// NFT (0, 3) gets a resource and NFT (0, 4) a property
+		let basic_resource =
+			BasicResource { src: None, metadata: None, license: None, thumb: None };
+		assert_ok!(RMRKCore::add_basic_resource(Origin::signed(ALICE), 0, 3, basic_resource));
+		assert_ok!(RMRKCore::set_property(
+			Origin::signed(ALICE),
+			0,
+			Some(4),
+			stbk("key"),
+			stb("value")
+		));
+		// Only the root owner can burn NFT (0, 0)
+		assert_noop!(
+			RMRKCore::burn_nft_lazy(Origin::signed(BOB), 0, 0),
+			Error::<Test>::NoPermission
+		);
+		// ALICE burns NFT (0, 0) lazily
+		assert_ok!(RMRKCore::burn_nft_lazy(Origin::signed(ALICE), 0, 0));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTBurned {
+			owner: ALICE,
+			nft_id: 0,
+		}));
+		assert!(RMRKCore::nfts(0, 0).is_none());
+		assert_eq!(UNQ::Pallet::<Test>::owner(0, 0), None);
+		assert_eq!(RMRKCore::collections(0).unwrap().nfts_count, 4);
+		// Its children are queued for deletion, leave ALICE's inventory and can no longer be sent
+		assert_eq!(RMRKCore::lazy_burns((0, 1)), Some(ALICE));
+		assert_eq!(RMRKCore::lazy_burns((0, 2)), Some(ALICE));
+		assert!(RMRKCore::nfts_by_root_owner(ALICE, (0, 1)).is_none());
+		assert!(RMRKCore::nfts_by_root_owner(ALICE, (0, 2)).is_none());
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(ALICE),
+				0,
+				1,
+				AccountIdOrCollectionNftTuple::AccountId(ALICE)
+			),
+			Error::<Test>::NoAvailableNftId
+		);
+		// An idle block with room for a single NFT deletes the first queued child, queueing
+		// NFT (0, 3) behind NFT (0, 2)
+		RMRKCore::on_idle(2, RMRKCore::lazy_burn_weight());
+		assert!(RMRKCore::nfts(0, 1).is_none());
+		assert!(RMRKCore::nfts(0, 2).is_some());
+		assert!(RMRKCore::nfts_by_root_owner(ALICE, (0, 3)).is_none());
+		RMRKCore::on_idle(3, RMRKCore::lazy_burn_weight());
+		assert!(RMRKCore::nfts(0, 2).is_none());
+		assert!(RMRKCore::nfts(0, 3).is_some());
+		assert_no_orphaned_keys();
+		// The rest of the tree is deleted once there is enough weight left
+		RMRKCore::on_idle(4, u64::MAX);
+		assert_eq!(LazyBurns::<Test>::iter().count(), 0);
+		assert_eq!(LazyBurnQueue::<Test>::iter().count(), 0);
+		for nft_id in 1..=4 {
+			assert!(RMRKCore::nfts(0, nft_id).is_none());
+			assert_eq!(UNQ::Pallet::<Test>::owner(0, nft_id), None);
+			assert!(System::events().iter().any(|record| record.event ==
+				MockEvent::RmrkCore(crate::Event::NFTBurned { owner: ALICE, nft_id })));
+		}
+		assert_eq!(Resources::<Test>::iter_prefix((0, 3)).count(), 0);
+		assert_eq!(Properties::<Test>::iter_prefix((0, Some(4))).count(), 0);
+		assert_eq!(RMRKCore::nfts_owned_by(&ALICE, 0, u32::MAX), vec![]);
+		// With every NFT deleted, the collection can be destroyed
+		assert_eq!(RMRKCore::collections(0).unwrap().nfts_count, 0);
+		assert_ok!(RMRKCore::destroy_collection(Origin::signed(ALICE), COLLECTION_ID_0));
+		assert_no_orphaned_keys();
+	});
+}
+
+/// NFT: Lazy burn removes the resources and properties of a queued NFT over several blocks
+#[test]
+fn burn_nft_lazy_removes_items_incrementally() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection and nest NFT (0, 1) in NFT (0, 0)
+		assert_ok!(basic_collection());
+		for _ in 0..2 {
+			assert_ok!(basic_mint());
+		}
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+		));
+		// NFT (0, 1) gets three resources, two properties and an owner property
+		let basic_resource =
+			BasicResource { src: None, metadata: None, license: None, thumb: None };
+		for _ in 0..3 {
+			assert_ok!(RMRKCore::add_basic_resource(
+				Origin::signed(ALICE),
+				0,
+				1,
+				basic_resource.clone()
+			));
+		}
+		for key in ["key-1", "key-2"] {
+			assert_ok!(RMRKCore::set_property(
+				Origin::signed(ALICE),
+				0,
+				Some(1),
+				stbk(key),
+				stb("value")
+			));
+		}
+		assert_ok!(RMRKCore::set_owner_property(
+			Origin::signed(ALICE),
+			0,
+			1,
+			stbk("nickname"),
+			stb("value")
+		));
+		// ALICE burns NFT (0, 0) lazily, queueing NFT (0, 1)
+		assert_ok!(RMRKCore::burn_nft_lazy(Origin::signed(ALICE), 0, 0));
+		// An idle block with room for two items removes two of the resources only, releasing
+		// their deposits
+		let reserved = Balances::reserved_balance(ALICE);
+		let weight = RMRKCore::item_removal_weight(2);
+		assert_eq!(RMRKCore::on_idle(2, weight), weight);
+		assert_eq!(Balances::reserved_balance(ALICE), reserved - 2 * ResourceDeposit::get());
+		assert_eq!(Resources::<Test>::iter_prefix((0, 1)).count(), 1);
+		assert_eq!(Properties::<Test>::iter_prefix((0, Some(1))).count(), 2);
+		assert_eq!(RMRKCore::lazy_burns((0, 1)), Some(ALICE));
+		// The next one resumes with the last resource and the properties, without room left
+		// for the NFT itself
+		let reserved = Balances::reserved_balance(ALICE);
+		let weight = RMRKCore::item_removal_weight(4);
+		assert_eq!(RMRKCore::on_idle(3, weight), weight);
+		let properties_deposit = 2 * RMRKCore::property_deposit(&stbk("key-1"), &stb("value")) +
+			RMRKCore::property_deposit(&stbk("nickname"), &stb("value"));
+		assert_eq!(
+			Balances::reserved_balance(ALICE),
+			reserved - ResourceDeposit::get() - properties_deposit
+		);
+		assert_eq!(Resources::<Test>::iter_prefix((0, 1)).count(), 0);
+		assert_eq!(Properties::<Test>::iter_prefix((0, Some(1))).count(), 0);
+		assert_eq!(OwnerProperties::<Test>::iter_prefix((0, 1)).count(), 0);
+		assert!(RMRKCore::nfts(0, 1).is_some());
+		// Then the NFT itself is deleted
+		let weight = RMRKCore::lazy_burn_weight();
+		assert_eq!(RMRKCore::on_idle(4, weight), weight);
+		assert!(RMRKCore::nfts(0, 1).is_none());
+		assert_eq!(RMRKCore::lazy_burns((0, 1)), None);
+		assert_eq!(LazyBurnQueue::<Test>::iter().count(), 0);
+		assert_no_orphaned_keys();
+	});
+}
+
+/// NFT: Burning a tree and destroying its collection leave no storage behind
+#[test]
+fn burn_and_destroy_leave_no_orphaned_keys() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection with a property and a shared resource
+		assert_ok!(basic_collection());
+		assert_ok!(RMRKCore::set_property(
+			Origin::signed(ALICE),
+			0,
+			None,
+			stbk("collection"),
+			stb("value")
+		));
+		let basic_resource =
+			BasicResource { src: None, metadata: None, license: None, thumb: None };
+		assert_ok!(RMRKCore::add_collection_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			ResourceTypes::Basic(basic_resource),
+		));
+		// Mint NFTs (0, 0), (0, 1) and (0, 2), nested in a chain
+		for _ in 0..3 {
+			assert_ok!(basic_mint());
+		}
+		for (nft_id, parent) in [(1, 0), (2, 1)] {
+			assert_ok!(RMRKCore::send(
+				Origin::signed(ALICE),
+				0,
+				nft_id,
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, parent),
+			));
+		}
+		// NFT (0, 1) gets equippable resources, priorities, properties, an opt-out, frozen
+		// metadata and a lock
+		assert_ok!(RMRKCore::add_composable_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			1,
+			ComposableResource {
+				parts: bvec![0, 1],
+				src: None,
+				base: 0,
+				license: None,
+				metadata: None,
+				slot: None,
+				thumb: None,
+			},
+		));
+		assert_ok!(RMRKCore::add_slot_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			1,
+			SlotResource {
+				src: None,
+				base: 0,
+				license: None,
+				metadata: None,
+				slot: 0,
+				thumb: None
+			},
+		));
+		assert_ok!(RMRKCore::set_priority(Origin::signed(ALICE), 0, 1, bvec![1, 0]));
+		assert_ok!(RMRKCore::set_property(
+			Origin::signed(ALICE),
+			0,
+			Some(1),
+			stbk("nft"),
+			stb("value")
+		));
+		assert_ok!(RMRKCore::set_owner_property(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			1,
+			stbk("nickname"),
+			stb("alice")
+		));
+		assert_ok!(RMRKCore::set_collection_resource_opt_out(Origin::signed(ALICE), 0, 1, 0, true));
+		assert_ok!(RMRKCore::set_nft_metadata(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			1,
+			stbd("frozen"),
+			true
+		));
+		RMRKCore::set_lock((0, 1), true);
+		// ALICE mints NFT (0, 3) for BOB, who sends it to NFT (0, 2), pending in ALICE's inbox
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(BOB),
+			COLLECTION_ID_0,
+			None,
+			bvec![0u8; 20],
+			true,
+			None
+		));
+		assert_ok!(RMRKCore::send(
+			Origin::signed(BOB),
+			0,
+			3,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+		));
+		assert!(RMRKCore::nfts(0, 3).unwrap().pending);
+		// ALICE burns NFT (0, 0) and with it the whole tree
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, MAX_BURNS));
+		for nft_id in 0..=3 {
+			assert!(RMRKCore::nfts(0, nft_id).is_none());
+			assert_eq!(UNQ::Pallet::<Test>::owner(0, nft_id), None);
+		}
+		assert_no_orphaned_keys();
+		// Destroying the collection removes what is left
+		assert_ok!(RMRKCore::destroy_collection(Origin::signed(ALICE), COLLECTION_ID_0));
+		assert_no_orphaned_keys();
+		assert!(!NextNftId::<Test>::contains_key(0));
+	});
+}
+
+/// NFT: Approved operators can send and burn NFTs on behalf of their root owner
+#[test]
+fn approvals_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		// Create a basic collection and mint NFTs (0, 0) and (0, 1) to ALICE
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint());
+		assert_ok!(basic_mint());
+		// BOB cannot send ALICE's NFT without approval
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(BOB),
+				0,
+				0,
+				AccountIdOrCollectionNftTuple::AccountId(BOB)
+			),
+			Error::<Test>::NoPermission
+		);
+		// Only the root owner can approve an operator
+		assert_noop!(
+			RMRKCore::approve(Origin::signed(CHARLIE), 0, 0, CHARLIE, None),
+			Error::<Test>::NoPermission
+		);
+		// ALICE approves BOB for NFT (0, 0) until block 10
+		assert_ok!(RMRKCore::approve(Origin::signed(ALICE), 0, 0, BOB, Some(10)));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::Approved {
+			owner: ALICE,
+			collection_id: 0,
+			nft_id: 0,
+			operator: BOB,
+			expiry: Some(10),
+		}));
+		// BOB sends NFT (0, 0) to CHARLIE, which clears the approval
+		assert_ok!(RMRKCore::send(
+			Origin::signed(BOB),
+			0,
+			0,
+			AccountIdOrCollectionNftTuple::AccountId(CHARLIE)
+		));
+		assert_eq!(UNQ::Pallet::<Test>::owner(0, 0), Some(CHARLIE));
+		assert!(RMRKCore::approvals((0, 0)).is_none());
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(BOB),
+				0,
+				0,
+				AccountIdOrCollectionNftTuple::AccountId(ALICE)
+			),
+			Error::<Test>::NoPermission
+		);
+		// An approval is no longer valid after its expiry
+		assert_ok!(RMRKCore::approve(Origin::signed(ALICE), 0, 1, BOB, Some(3)));
+		System::set_block_number(4);
+		assert_noop!(
+			RMRKCore::burn_nft(Origin::signed(BOB), 0, 1, MAX_BURNS),
+			Error::<Test>::NoPermission
+		);
+		// ALICE cancels the approval, which can only be done once
+		assert_ok!(RMRKCore::cancel_approval(Origin::signed(ALICE), 0, 1));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ApprovalCancelled {
+			owner: ALICE,
+			collection_id: 0,
+			nft_id: 1,
+		}));
+		assert_noop!(
+			RMRKCore::cancel_approval(Origin::signed(ALICE), 0, 1),
+			Error::<Test>::ApprovalNotFound
+		);
+		// ALICE approves BOB for all of ALICE's NFTs, so BOB can burn NFT (0, 1)
+		assert_ok!(RMRKCore::set_approval_for_all(Origin::signed(ALICE), BOB, true));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ApprovalForAll {
+			owner: ALICE,
+			operator: BOB,
+			approved: true,
+		}));
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(BOB), 0, 1, MAX_BURNS));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTBurned {
+			owner: ALICE,
+			nft_id: 1,
+		}));
+		// Once revoked, BOB can no longer act on ALICE's NFTs
+		assert_ok!(RMRKCore::set_approval_for_all(Origin::signed(ALICE), BOB, false));
+		assert_ok!(basic_mint());
+		assert_noop!(
+			RMRKCore::burn_nft(Origin::signed(BOB), 0, 2, MAX_BURNS),
+			Error::<Test>::NoPermission
+		);
+		// An operator nesting an NFT acts as its root owner, who pays the child deposit
+		assert_ok!(basic_mint());
+		assert_ok!(RMRKCore::approve(Origin::signed(ALICE), 0, 3, BOB, None));
+		let bob_reserved = Balances::reserved_balance(BOB);
+		assert_ok!(RMRKCore::send(
+			Origin::signed(BOB),
+			0,
+			3,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+		));
+		assert_eq!(RMRKCore::child_deposits((0, 3)), Some((ALICE, ChildDeposit::get())));
+		assert_eq!(Balances::reserved_balance(BOB), bob_reserved);
+		// Approvals on descendants are cleared when their tree changes root owner
+		assert_ok!(RMRKCore::approve(Origin::signed(ALICE), 0, 3, BOB, None));
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			2,
+			AccountIdOrCollectionNftTuple::AccountId(CHARLIE)
+		));
+		assert!(RMRKCore::approvals((0, 3)).is_none());
+		assert_no_orphaned_keys();
+	});
+}
+
+/// NFT: Pending NFTs time out and return to their senders
+#[test]
+fn pending_nft_timeout_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		use frame_support::traits::Hooks;
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT (0, 0) to BOB at block 1
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(BOB),
+			COLLECTION_ID_0,
+			None,
+			bvec![0u8; 20],
+			true,
+			None
+		));
+		assert_eq!(RMRKCore::pending_nfts(0, 0).unwrap().since, 1);
+		assert!(RMRKCore::pending_nft_queue(1, (0, 0)).is_some());
+		// Accepting the NFT takes it off the queue
+		assert_ok!(RMRKCore::accept_nfts(Origin::signed(BOB), bvec![(0, 0)]));
+		assert!(RMRKCore::pending_nft_queue(1, (0, 0)).is_none());
+		// ALICE sends NFT (0, 1) to BOB's NFT (0, 0) at block 1
+		assert_ok!(basic_mint());
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+		));
+		// ALICE mints NFT (0, 2) to BOB at block 5
+		System::set_block_number(5);
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(BOB),
+			COLLECTION_ID_0,
+			None,
+			bvec![0u8; 20],
+			true,
+			None
+		));
+
+		// Nothing has timed out before block 11
+		RMRKCore::on_idle(10, u64::MAX);
+		assert!(RMRKCore::nfts(0, 1).unwrap().pending);
+		// At block 11, NFT (0, 1) is returned to ALICE
+		RMRKCore::on_idle(11, u64::MAX);
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PendingNftTimedOut {
+			collection_id: 0,
+			nft_id: 1,
+			returned_to: AccountIdOrCollectionNftTuple::AccountId(ALICE),
+		}));
+		let nft = RMRKCore::nfts(0, 1).unwrap();
+		assert!(!nft.pending);
+		assert_eq!(nft.owner, AccountIdOrCollectionNftTuple::AccountId(ALICE));
+		assert_eq!(UNQ::Pallet::<Test>::owner(0, 1), Some(ALICE));
+		assert!(RMRKCore::children((0, 0), (0, 1)).is_none());
+		assert!(RMRKCore::pending_nfts(0, 1).is_none());
+		assert!(RMRKCore::pending_nft_queue(1, (0, 1)).is_none());
+		assert_eq!(RMRKCore::pending_sweep_cursor(), 2);
+		// NFT (0, 2) is still pending
+		assert!(RMRKCore::nfts(0, 2).unwrap().pending);
+
+		// Without enough weight, the sweep does nothing
+		assert_eq!(RMRKCore::on_idle(15, 0), 0);
+		assert!(RMRKCore::nfts(0, 2).unwrap().pending);
+		// With enough weight, NFT (0, 2) is returned to ALICE
+		RMRKCore::on_idle(15, u64::MAX);
+		assert_eq!(UNQ::Pallet::<Test>::owner(0, 2), Some(ALICE));
+		assert!(!RMRKCore::nfts(0, 2).unwrap().pending);
+		assert!(RMRKCore::pending_inbox_of(&BOB, 0, u32::MAX).is_empty());
+		assert_eq!(RMRKCore::pending_sweep_cursor(), 6);
+	});
+}
+
+/// NFT: Send tests, siblings (RMRK2.0 spec: SEND)
+#[test]
+fn send_two_nfts_to_same_nft_creates_two_children() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFTs (0, 0), (0, 1), (0, 2)
+		for _ in 0..3 {
+			assert_ok!(basic_mint());
+		}
+		// ALICE sends NFT (0, 1) to NFT (0, 0)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+		));
+		// NFT (0,0) has NFT (0,1) in Children StorageMap
+		assert!(RMRKCore::children((0, 0), (0, 1)).is_some());
+		// ALICE sends NFT (0, 2) to NFT (0, 0)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			2,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+		));
+		// NFT (0,0) has NFT (0,1) & (0,2) in Children StorageMap
+		assert!(RMRKCore::children((0, 0), (0, 1)).is_some());
+		assert!(RMRKCore::children((0, 0), (0, 2)).is_some());
+	});
+}
+
+/// NFT: Send tests, removing parent (RMRK2.0 spec: SEND)
+#[test]
+fn send_nft_removes_existing_parent() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFTs (0, 0), (0, 1), (0, 2), (0, 3)
+		for _ in 0..4 {
+			assert_ok!(basic_mint());
+		}
+		// ALICE sends NFT (0, 1) to NFT (0, 0)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+		));
+		// ALICE sends NFT (0, 2) to NFT (0, 0)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			2,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+		));
+		// NFT (0, 0) is parent of NFT (0, 1)
+		assert!(RMRKCore::children((0, 0), (0, 1)).is_some());
+		assert!(RMRKCore::children((0, 0), (0, 2)).is_some());
+		// ALICE sends NFT (0, 1) to NFT (0, 2)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+		));
+		// NFT (0, 0) is no longer parent of NFT (0, 1)
+		assert!(RMRKCore::children((0, 0), (0, 1)).is_none());
+	});
+}
+
+/// NFT: Send tests, multi-generational circular testing (RMRK2.0 spec: SEND)
+#[test]
+fn send_to_grandchild_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFTs (0, 0), (0, 1), (0, 2)
+		for _ in 0..3 {
+			assert_ok!(basic_mint());
+		}
+		// Alice sends NFT (0, 1) to NFT (0, 0)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+		));
+		// Alice sends NFT (0, 2) to NFT (0, 1)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			2,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 1),
+		));
+		// Sending NFT to its own grandchild should fail
+		assert_noop!(
+			RMRKCore::send(
+				Origin::signed(ALICE),
+				0,
+				0,
+				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+			),
+			Error::<Test>::CannotSendToDescendentOrSelf
+		);
+	});
+}
+
+/// NFT: Burn simple tests (RMRK2.0 spec: BURN)
+#[test]
+fn burn_nft_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint an NFT
+		assert_ok!(basic_mint());
+		// Add two resources to NFT (to test if burning also burns the resources)
+
+		let basic_resource =
+			BasicResource { src: None, metadata: None, license: None, thumb: None };
+
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			0,
+			0,
+			basic_resource.clone(),
+		));
+
+		assert_ok!(RMRKCore::add_basic_resource(Origin::signed(ALICE), 0, 0, basic_resource,));
+
+		// Ensure resources are there
+		assert_eq!(Resources::<Test>::iter_prefix((COLLECTION_ID_0, NFT_ID_0)).count(), 2);
+
+		// BOB should not be able to burn ALICE's NFT
+		assert_noop!(
+			RMRKCore::burn_nft(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, MAX_BURNS),
+			Error::<Test>::NoPermission
+		);
+		// ALICE burns her NFT
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, MAX_BURNS));
+		// Successful burn creates NFTBurned event
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTBurned {
+			owner: ALICE,
+			nft_id: 0,
+		}));
+		// NFT count of collection is now 0
+		assert_eq!(RMRKCore::collections(COLLECTION_ID_0).unwrap().nfts_count, 0);
+		// ALICE can't burn an NFT twice
+		assert_noop!(
+			RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, MAX_BURNS),
+			Error::<Test>::NoAvailableNftId
+		);
+		// Burned NFT no longer exists
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).is_none(), true);
+		// Resources associated with the NFT should no longer exist
+		assert_eq!(Resources::<Test>::iter_prefix((COLLECTION_ID_0, NFT_ID_0)).count(), 0);
+	});
+}
+
+/// NFT: Burn complex multi-generational tests (RMRK2.0 spec: BURN)
+#[test]
+fn burn_nft_with_great_grandchildren_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFTs (0, 0), (0, 1), (0, 2), (0, 3)
+		for _ in 0..4 {
+			assert_ok!(basic_mint());
+		}
+
+		let basic_resource =
+			BasicResource { src: None, metadata: None, license: None, thumb: None };
+
+		// Add two resources to the great-grandchild (0, 3)
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			3,
+			basic_resource.clone(),
+		));
+
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			3,
+			basic_resource,
+		));
+
+		// Ensure resources are there
+		assert_eq!(Resources::<Test>::iter_prefix((COLLECTION_ID_0, 3)).count(), 2);
+
+		// ALICE sends NFT (0, 1) to NFT (0, 0)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+		));
+		// ALICE sends NFT (0, 2) to NFT (0, 1)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			2,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 1),
+		));
+		// ALICE sends NFT (0, 3) to NFT (0, 2)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			3,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+		));
+		// Great-grandchild NFT (0, 3) exists
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 3).is_some(), true);
+		// Burn great-grandparent NFT (0, 0)
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, MAX_BURNS));
+		// Great-grandchild NFT (0, 3) is dead :'-(
+		assert!(RMRKCore::nfts(COLLECTION_ID_0, 3).is_none());
+		// Great-grandchild resources are gone
+		assert_eq!(Resources::<Test>::iter_prefix((COLLECTION_ID_0, 3)).count(), 0);
+	});
+}
+
+/// NFT: Burn beyond max_recursions fails gracefully
+#[test]
+fn burn_nft_beyond_max_recursions_fails_gracefully() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFTs (0, 0), (0, 1), (0, 2), (0, 3)
+		for _ in 0..5 {
+			assert_ok!(basic_mint());
+		}
+		// ALICE sends NFT (0, 1) to NFT (0, 0)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+		));
+		// ALICE sends NFT (0, 2) to NFT (0, 1)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			2,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 1),
+		));
+		// ALICE sends NFT (0, 3) to NFT (0, 2)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			3,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+		));
+		// ALICE sends NFT (0, 4) to NFT (0, 3)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			4,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 3),
+		));
+		// All NFTs exist
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 0).is_some(), true);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 1).is_some(), true);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 2).is_some(), true);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 3).is_some(), true);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 4).is_some(), true);
+		// Burn great-grandparent NFT (0, 0)
+		assert_noop!(
+			RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, MAX_BURNS),
+			Error::<Test>::TooManyRecursions
+		);
+		// All NFTs still exist
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 0).is_some(), true);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 1).is_some(), true);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 2).is_some(), true);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 3).is_some(), true);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 4).is_some(), true);
+		// Allowing one more level burns the whole tree, down to MaxRecursions
+		assert_ok!(RMRKCore::burn_nft(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			MAX_BURNS + 1
+		));
+		assert_eq!(Nfts::<Test>::iter().count(), 0);
+		assert_eq!(UNQ::Pallet::<Test>::owner(COLLECTION_ID_0, 4), None);
+		assert_no_orphaned_keys();
+	});
+}
+
+/// NFT: Burn child removes NFT from owner-NFT's Children list
+#[test]
+fn burn_child_nft_removes_parents_children() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFTs (0, 0), (0, 1), (0, 2), (0, 3)
+		for _ in 0..2 {
+			assert_ok!(basic_mint());
+		}
+		// ALICE sends NFT (0, 1) to NFT (0, 0)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+		));
+		// All NFTs exist
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 0).is_some(), true);
+		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 1).is_some(), true);
+		// NFT (0, 0) should have 1 Children storage member
+		assert_eq!(Children::<Test>::iter_prefix((0, 0)).count(), 1);
+		// Burn NFT (0, 1)
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), 0, 1, MAX_BURNS),);
+		// NFT (0, 0) should have 0 Children storage members
+		assert_eq!(Children::<Test>::iter_prefix((0, 0)).count(), 0);
+	});
+}
+
+/// NFT: The root owner index follows mints, sends, rejections and burns of nested NFTs
+#[test]
+fn nfts_by_root_owner_index_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Sorted inventory of an account
+		let inventory = |account| {
+			let mut nfts = RMRKCore::nfts_owned_by(&account, 0, u32::MAX);
+			nfts.sort();
+			nfts
+		};
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFTs (0, 0) and (0, 1) to ALICE
+		assert_ok!(basic_mint());
+		assert_ok!(basic_mint());
+		// Mint NFT (0, 2) directly into NFT (0, 1)
+		assert_ok!(RMRKCore::mint_nft_directly_to_nft(
+			Origin::signed(ALICE),
+			(0, 1),
+			COLLECTION_ID_0,
+			None,
+			bvec![0u8; 20],
+			true,
+			None
+		));
+		// Mint NFT (0, 3) to BOB
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(BOB),
+			COLLECTION_ID_0,
+			None,
+			bvec![0u8; 20],
+			true,
+			None
+		));
+		// ALICE root-owns the nested NFT (0, 2) too
+		assert_eq!(inventory(ALICE), vec![(0, 0), (0, 1), (0, 2)]);
+		assert_eq!(inventory(BOB), vec![(0, 3)]);
+		// The index can be paged through
+		assert_eq!(RMRKCore::nfts_owned_by(&ALICE, 0, 2).len(), 2);
+		assert_eq!(RMRKCore::nfts_owned_by(&ALICE, 2, 2).len(), 1);
+		assert!(RMRKCore::nfts_owned_by(&ALICE, 3, 2).is_empty());
+
+		// ALICE sends NFT (0, 1) with its child to BOB-owned NFT (0, 3)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 3),
+		));
+		// BOB now root-owns the whole tree, though NFT (0, 1) is pending
+		assert_eq!(inventory(ALICE), vec![(0, 0)]);
+		assert_eq!(inventory(BOB), vec![(0, 1), (0, 2), (0, 3)]);
+		// BOB rejects NFT (0, 1), which returns to ALICE with its child
+		assert_ok!(RMRKCore::reject_nft(Origin::signed(BOB), 0, 1, false));
+		assert_eq!(inventory(ALICE), vec![(0, 0), (0, 1), (0, 2)]);
+		assert_eq!(inventory(BOB), vec![(0, 3)]);
+
+		// ALICE sends NFT (0, 0) to BOB's account
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB)
+		));
+		assert_eq!(inventory(BOB), vec![(0, 0), (0, 3)]);
+
+		// Burning NFT (0, 1) removes it and its child from the index
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), 0, 1, MAX_BURNS));
+		assert!(inventory(ALICE).is_empty());
+	});
+}
+
+/// NFT: Migration populates the root owner index
+#[test]
+fn nfts_by_root_owner_migration_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		use frame_support::traits::{GetStorageVersion, StorageVersion};
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT (0, 0) to ALICE and NFT (0, 1) directly into it
+		assert_ok!(basic_mint());
+		assert_ok!(RMRKCore::mint_nft_directly_to_nft(
+			Origin::signed(ALICE),
+			(0, 0),
+			COLLECTION_ID_0,
+			None,
+			bvec![0u8; 20],
+			true,
+			None
+		));
+		// Clear the index as it was before v3
+		StorageVersion::new(2).put::<RMRKCore>();
+		NftsByRootOwner::<Test>::remove_all(None);
+		// Run the migration
+		crate::migration::v3::migrate::<Test>();
+		// ALICE root-owns both NFTs
+		assert!(RMRKCore::nfts_by_root_owner(ALICE, (0, 0)).is_some());
+		assert!(RMRKCore::nfts_by_root_owner(ALICE, (0, 1)).is_some());
+		assert_eq!(RMRKCore::on_chain_storage_version(), StorageVersion::new(3));
+	});
+}
+
+/// Migration: NFTs pending before their origin was recorded can be rejected
+#[test]
+fn pending_nfts_migration_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		use frame_support::traits::{GetStorageVersion, StorageVersion};
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// ALICE mints (0, 0) for ALICE and (0, 1) for BOB
+		assert_ok!(basic_mint());
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(BOB),
+			COLLECTION_ID_0,
+			None,
+			bvec![0u8; 20],
+			true,
+			None
+		));
+		// BOB sends NFT (0, 1) to ALICE's NFT (0, 0), where it is pending
+		assert_ok!(RMRKCore::send(
+			Origin::signed(BOB),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+		));
+		// Store the pending NFT as it was before its origin was recorded: unindexed and still
+		// owned by BOB
+		StorageVersion::new(7).put::<RMRKCore>();
+		PendingNfts::<Test>::remove_all(None);
+		PendingNftQueue::<Test>::remove_all(None);
+		PendingInbox::<Test>::remove_all(None);
+		Nfts::<Test>::mutate(0, 1, |nft| {
+			nft.as_mut().unwrap().owner = AccountIdOrCollectionNftTuple::AccountId(BOB)
+		});
+		// Run the migration
+		crate::migration::v8::migrate::<Test>();
+		// NFT (0, 1) is owned by NFT (0, 0), pending for ALICE and was sent by BOB
+		assert_eq!(
+			RMRKCore::nfts(0, 1).unwrap().owner,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0)
+		);
+		let pending_info = RMRKCore::pending_nfts(0, 1).unwrap();
+		assert_eq!(pending_info.sender, BOB);
+		assert_eq!(pending_info.previous_owner, AccountIdOrCollectionNftTuple::AccountId(BOB));
+		assert!(RMRKCore::pending_inbox(ALICE, PendingItem::Nft { collection_id: 0, nft_id: 1 })
+			.is_some());
+		assert_eq!(RMRKCore::on_chain_storage_version(), StorageVersion::new(8));
+		// ALICE rejects NFT (0, 1), which is returned to BOB
+		assert_ok!(RMRKCore::reject_nft(Origin::signed(ALICE), 0, 1, false));
+		assert_eq!(UNQ::Pallet::<Test>::owner(0, 1), Some(BOB));
+	});
+}
+
+/// Migration: NFTs awaiting a lazy burn before the queue existed are queued
+#[test]
+fn lazy_burn_queue_migration_works() {
+	use frame_support::traits::{GetStorageVersion, StorageVersion};
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(basic_collection());
+		for _ in 0..2 {
+			assert_ok!(basic_mint());
+		}
+		// NFT (0, 1) was queued for deletion before v9, and is still in ALICE's inventory
+		LazyBurns::<Test>::insert((0, 1), ALICE);
+		StorageVersion::new(8).put::<RMRKCore>();
+		crate::migration::v9::migrate::<Test>();
+		assert_eq!(RMRKCore::lazy_burn_queue(0), Some((0, 1)));
+		assert_eq!(RMRKCore::lazy_burn_queue_bounds(), (0, 1));
+		assert!(RMRKCore::nfts_by_root_owner(ALICE, (0, 1)).is_none());
+		assert_eq!(RMRKCore::on_chain_storage_version(), StorageVersion::new(9));
+		// NFT (0, 1) gets deleted in the next idle block
+		RMRKCore::on_idle(2, u64::MAX);
+		assert!(RMRKCore::nfts(0, 1).is_none());
+		assert_eq!(RMRKCore::lazy_burn_queue_bounds(), (1, 1));
+		assert_no_orphaned_keys();
+	});
+}
+
+/// NFT: The pending inbox lists what awaits the decision of a root owner
+#[test]
+fn pending_inbox_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Sorted pending inbox of an account
+		let inbox = |account| {
+			let mut items = RMRKCore::pending_inbox_of(&account, 0, u32::MAX);
+			items.sort_by_key(|item| item.encode());
+			items
+		};
+		let basic_resource = |src: &str| BasicResource {
+			src: Some(stbd(src)),
+			metadata: None,
+			license: None,
+			thumb: None,
+		};
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT (0, 0) to ALICE and NFT (0, 1) to BOB, which is pending
+		assert_ok!(basic_mint());
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(BOB),
+			COLLECTION_ID_0,
+			None,
+			bvec![0u8; 20],
+			true,
+			None
+		));
+		assert!(inbox(ALICE).is_empty());
+		assert_eq!(inbox(BOB), vec![PendingItem::Nft { collection_id: 0, nft_id: 1 }]);
+		// BOB accepts NFT (0, 1)
+		assert_ok!(RMRKCore::accept_nft(
+			Origin::signed(BOB),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::AccountId(BOB)
+		));
+		assert!(inbox(BOB).is_empty());
+
+		// ALICE adds a resource to NFT (0, 1), pending for BOB
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			0,
+			1,
+			basic_resource("res-0")
+		));
+		let addition =
+			PendingItem::ResourceAddition { collection_id: 0, nft_id: 1, resource_id: 0 };
+		assert_eq!(inbox(BOB), vec![addition]);
+		assert_ok!(RMRKCore::accept_resource(Origin::signed(BOB), 0, 1, 0));
+		assert!(inbox(BOB).is_empty());
+		// ALICE proposes a replacement, which BOB accepts
+		assert_ok!(RMRKCore::replace_resource(
+			Origin::signed(ALICE),
+			0,
+			1,
+			0,
+			ResourceTypes::Basic(basic_resource("res-1"))
+		));
+		assert_eq!(
+			inbox(BOB),
+			vec![PendingItem::ResourceReplacement { collection_id: 0, nft_id: 1, resource_id: 0 }]
+		);
+		assert_ok!(RMRKCore::accept_resource_replacement(Origin::signed(BOB), 0, 1, 0));
+		assert!(inbox(BOB).is_empty());
+		// ALICE requests the removal of the resource, which BOB accepts
+		assert_ok!(RMRKCore::remove_resource(Origin::signed(ALICE), 0, 1, 0));
+		assert_eq!(
+			inbox(BOB),
+			vec![PendingItem::ResourceRemoval { collection_id: 0, nft_id: 1, resource_id: 0 }]
+		);
+		assert_ok!(RMRKCore::accept_resource_removal(Origin::signed(BOB), 0, 1, 0));
+		assert!(inbox(BOB).is_empty());
+
+		// ALICE sends NFT (0, 0) to BOB's NFT (0, 1) and adds a resource to it
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			0,
+			0,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 1),
+		));
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			0,
+			0,
+			basic_resource("res-2")
+		));
+		let addition =
+			PendingItem::ResourceAddition { collection_id: 0, nft_id: 0, resource_id: 0 };
+		assert_eq!(inbox(BOB), {
+			let mut items = vec![PendingItem::Nft { collection_id: 0, nft_id: 0 }, addition];
+			items.sort_by_key(|item| item.encode());
+			items
+		});
+		// BOB rejects NFT (0, 0), whose pending resource now awaits ALICE
+		assert_ok!(RMRKCore::reject_nft(Origin::signed(BOB), 0, 0, false));
+		assert!(inbox(BOB).is_empty());
+		assert_eq!(inbox(ALICE), vec![addition]);
+		// ALICE rejects the resource
+		assert_ok!(RMRKCore::reject_resource(Origin::signed(ALICE), 0, 0, 0));
+		assert!(inbox(ALICE).is_empty());
+
+		// ALICE adds another resource to BOB's NFT (0, 1), then BOB burns it
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			0,
+			1,
+			basic_resource("res-3")
+		));
+		assert_eq!(inbox(BOB).len(), 1);
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(BOB), 0, 1, MAX_BURNS));
+		assert!(inbox(BOB).is_empty());
+	});
+}
+
+/// Resource: Basic resource addition (RMRK2.0 spec: RESADD)
+#[test]
+fn create_resource_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let basic_resource =
+			BasicResource { src: None, metadata: None, license: None, thumb: None };
+
+		// Adding a resource to non-existent NFT should fail
+		assert_noop!(
+			RMRKCore::add_basic_resource(
+				Origin::signed(ALICE),
+				0, // collection_id
+				0, // nft_id
+				basic_resource,
+			),
+			Error::<Test>::CollectionUnknown
+		);
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT
+		assert_ok!(basic_mint());
+
+		let basic_resource =
+			BasicResource { src: None, metadata: None, license: None, thumb: None };
+
+		// Add resource to NFT
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			basic_resource,
+		));
+		// Successful resource addition should trigger ResourceAdded event
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceAdded {
+			nft_id: 0,
+			resource_id: 0, // resource_id
+		}));
+		// Since ALICE rootowns NFT, pending status of resource should be false
+		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().pending, false);
+
+		// Create Composable resource
+		let composable_resource = ComposableResource {
+			parts: vec![0, 1].try_into().unwrap(), // BoundedVec of Parts
+			src: Some(stbd("composable-resource")),
+			base: 0, // BaseID
+			license: None,
+			metadata: None,
+			slot: None,
+			thumb: None,
+		};
+
+		// Composable resource addition works
+		assert_ok!(RMRKCore::add_composable_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			composable_resource,
+		));
+
+		// Create Slot resource
+		let slot_resource = SlotResource {
+			src: Some(stbd("slot-resource")),
+			base: 0, // BaseID
+			license: None,
+			metadata: None,
+			slot: 0, // SlotID
+			thumb: None,
+		};
+
+		// Slot resource addition works
+		assert_ok!(RMRKCore::add_slot_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			slot_resource,
+		));
+	});
+}
+
+/// Minting with resources works
+#[test]
+fn add_resource_on_mint_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let basic_resource: BasicResource<BoundedVec<u8, UniquesStringLimit>> =
+			BasicResource { src: None, metadata: None, license: None, thumb: None };
+
+		// Create a basic collection
+		assert_ok!(basic_collection());
+
+		let basic_resource =
+			BasicResource { src: None, metadata: None, license: None, thumb: None };
+
+		// Resources to add
+		let resources_to_add = bvec![
+			ResourceTypes::Basic(basic_resource.clone()),
+			ResourceTypes::Basic(basic_resource),
+		];
+
+		// Mint NFT
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(ALICE),
+			COLLECTION_ID_0,
+			royalty(ALICE, Permill::from_float(1.525)),
+			bvec![0u8; 20],
+			true,
+			Some(resources_to_add),
+		));
+
+		assert_eq!(RMRKCore::resources((0, 0, 0)).is_some(), true);
+		assert_eq!(RMRKCore::resources((0, 0, 1)).is_some(), true);
+	});
+}
+
+/// Minting with more than max resources (set to 3 in mock) should panic
+#[should_panic]
+#[test]
+fn add_resource_on_mint_beyond_max_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+
+		let basic_resource =
+			BasicResource { src: None, metadata: None, license: None, thumb: None };
+
+		// Resources to add
+		let resources_to_add = bvec![
+			ResourceTypes::Basic(basic_resource.clone()),
+			ResourceTypes::Basic(basic_resource.clone()),
+			ResourceTypes::Basic(basic_resource.clone()),
+			ResourceTypes::Basic(basic_resource),
+		];
+
+		// Mint NFT
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(ALICE),
+			COLLECTION_ID_0,
+			royalty(ALICE, Permill::from_float(1.525)),
+			bvec![0u8; 20],
+			true,
+			Some(resources_to_add),
+		));
+	});
+}
+
+/// Resource: Resource addition with pending and accept (RMRK2.0 spec: ACCEPT)
+#[test]
+fn add_resource_pending_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(BOB),
+			COLLECTION_ID_0,
+			royalty(BOB, Permill::from_float(1.525)),
+			bvec![0u8; 20],
+			true,
+			None
+		));
+
+		let basic_resource = BasicResource {
+			src: Some(stbd("res-src")),
+			metadata: None,
+			license: None,
+			thumb: None,
+		};
+
+		// Since BOB isn't collection issuer, he can't add resources
+		assert_noop!(
+			RMRKCore::add_basic_resource(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				basic_resource.clone(),
+			),
+			Error::<Test>::NoPermission
+		);
+
+		// Collection issuer can add resource
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			basic_resource
+		));
+
+		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().pending, true);
+		// ALICE doesn't own BOB's NFT, so accept should fail
+		assert_noop!(
+			RMRKCore::accept_resource(Origin::signed(ALICE), 0, 0, 0),
+			Error::<Test>::NoPermission
+		);
+		// BOB can accept his own NFT's pending resource
+		assert_ok!(RMRKCore::accept_resource(Origin::signed(BOB), 0, 0, 0));
+		// Valid resource acceptance should trigger a ResourceAccepted event
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceAccepted {
+			nft_id: 0,
+			resource_id: 0, // resource_id
+		}));
+		// Resource should now have false pending status
+		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().pending, false);
+		// Accepting resource again should fail with ResourceNotPending
+		assert_noop!(
+			RMRKCore::accept_resource(Origin::signed(BOB), 0, 0, 0),
+			Error::<Test>::ResourceNotPending
+		);
+	});
+}
+
+/// Resource: Adding one resource to many NFTs keeps per-NFT pending logic
+#[test]
+fn add_resource_to_nfts_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT 0 to ALICE, NFT 1 to BOB and NFT 2 to ALICE
+		assert_ok!(basic_mint());
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(BOB),
+			COLLECTION_ID_0,
+			None,
+			bvec![0u8; 20],
+			true,
+			None
+		));
+		assert_ok!(basic_mint());
+
+		let resource = ResourceTypes::Basic(BasicResource {
+			src: Some(stbd("res-src")),
+			metadata: None,
+			license: None,
+			thumb: None,
+		});
+
+		// BOB isn't the collection issuer, so he can't add resources
+		assert_noop!(
+			RMRKCore::add_resource_to_nfts(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				ResourceTargets::Nfts(bvec![0, 1]),
+				resource.clone(),
+			),
+			Error::<Test>::NoPermission
+		);
+		// An explicit list fails as a whole if one of the NFTs doesn't exist
+		assert_noop!(
+			RMRKCore::add_resource_to_nfts(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				ResourceTargets::Nfts(bvec![0, 4]),
+				resource.clone(),
+			),
+			Error::<Test>::NoAvailableNftId
+		);
+		// Empty, inverted and oversized targets are rejected
+		assert_noop!(
+			RMRKCore::add_resource_to_nfts(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				ResourceTargets::Nfts(bvec![]),
+				resource.clone(),
+			),
+			Error::<Test>::InvalidResourceTargets
+		);
+		assert_noop!(
+			RMRKCore::add_resource_to_nfts(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				ResourceTargets::Range { first: 2, last: 1 },
+				resource.clone(),
+			),
+			Error::<Test>::InvalidResourceTargets
+		);
+		assert_noop!(
+			RMRKCore::add_resource_to_nfts(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				ResourceTargets::Range { first: 0, last: MaxBulkResources::get() },
+				resource.clone(),
+			),
+			Error::<Test>::InvalidResourceTargets
+		);
+
+		// ALICE adds the resource to NFTs 0 and 1
+		assert_ok!(RMRKCore::add_resource_to_nfts(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			ResourceTargets::Nfts(bvec![0, 1]),
+			resource.clone(),
+		));
+		// A ResourceAdded event is emitted for each NFT
+		System::assert_has_event(MockEvent::RmrkCore(crate::Event::ResourceAdded {
+			nft_id: 0,
+			resource_id: 0,
+		}));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceAdded {
+			nft_id: 1,
+			resource_id: 0,
+		}));
+		// The resource is accepted on ALICE's NFT and pending on BOB's NFT
+		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().pending, false);
+		assert_eq!(RMRKCore::resources((0, 1, 0)).unwrap().pending, true);
+
+		// Burn NFT 1 so that a range skips it
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(BOB), COLLECTION_ID_0, 1, MAX_BURNS));
+		// A range past the last minted NFT adds the resource to existing NFTs only
+		assert_ok!(RMRKCore::add_resource_to_nfts(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			ResourceTargets::Range { first: 0, last: 3 },
+			resource,
+		));
+		assert!(RMRKCore::resources((0, 0, 1)).is_some());
+		assert!(RMRKCore::resources((0, 2, 0)).is_some());
+		assert!(RMRKCore::resources((0, 1, 0)).is_none());
+		assert!(RMRKCore::resources((0, 3, 0)).is_none());
+	});
+}
+
+/// Resource: Rejecting a pending resource deletes it
+#[test]
+fn reject_resource_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT to BOB
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(BOB),
+			COLLECTION_ID_0,
+			None,
+			bvec![0u8; 20],
+			true,
+			None
+		));
+		let reserved = Balances::reserved_balance(ALICE);
+		// ALICE adds a composable resource with a slot, pending for BOB
+		assert_ok!(RMRKCore::add_composable_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			ComposableResource {
+				parts: bvec![0, 1],
+				src: None,
+				base: 0,
+				license: None,
+				metadata: None,
+				slot: Some((1, 2)),
+				thumb: None,
+			}
+		));
+		assert!(RMRKCore::equippable_bases((0, 0, 0)).is_some());
+		assert!(RMRKCore::equippable_slots((0, 0, 0, 1, 2)).is_some());
+		// ALICE doesn't own BOB's NFT, so reject should fail
+		assert_noop!(
+			RMRKCore::reject_resource(Origin::signed(ALICE), 0, 0, 0),
+			Error::<Test>::NoPermission
+		);
+		// Rejecting a resource that doesn't exist fails
+		assert_noop!(
+			RMRKCore::reject_resource(Origin::signed(BOB), 0, 0, 1),
+			Error::<Test>::ResourceDoesntExist
+		);
+		// BOB rejects the pending resource
+		assert_ok!(RMRKCore::reject_resource(Origin::signed(BOB), 0, 0, 0));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceRejected {
+			nft_id: 0,
+			resource_id: 0,
+		}));
+		// Resource and its equip indexes are gone, and the deposit is released
+		assert_eq!(RMRKCore::resources((0, 0, 0)), None);
+		assert_eq!(RMRKCore::equippable_bases((0, 0, 0)), None);
+		assert_eq!(RMRKCore::equippable_slots((0, 0, 0, 1, 2)), None);
+		assert_eq!(Balances::reserved_balance(ALICE), reserved);
+		// Accepted resources cannot be rejected
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			BasicResource { src: None, metadata: None, license: None, thumb: None },
+		));
+		assert_ok!(RMRKCore::accept_resource(Origin::signed(BOB), 0, 0, 1));
+		assert_noop!(
+			RMRKCore::reject_resource(Origin::signed(BOB), 0, 0, 1),
+			Error::<Test>::ResourceNotPending
+		);
+	});
+}
+
+/// Resource: Replacing a resource keeps its ID and priority
+#[test]
+fn replace_resource_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT to BOB
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(BOB),
+			COLLECTION_ID_0,
+			None,
+			bvec![0u8; 20],
+			true,
+			None
+		));
+		let basic_resource = |src: &str| {
+			ResourceTypes::Basic(BasicResource {
+				src: Some(stbd(src)),
+				metadata: None,
+				license: None,
+				thumb: None,
+			})
+		};
+		let composable_resource = ResourceTypes::Composable(ComposableResource {
+			parts: bvec![0, 1],
+			src: Some(stbd("level-2")),
+			base: 0,
+			license: None,
+			metadata: None,
+			slot: Some((0, 1)),
+			thumb: None,
+		});
+		// ALICE adds a basic resource, pending for BOB
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			BasicResource {
+				src: Some(stbd("level-0")),
+				metadata: None,
+				license: None,
+				thumb: None
+			},
+		));
+		// Replacing a pending resource replaces it in place
+		assert_ok!(RMRKCore::replace_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			0,
+			basic_resource("level-1")
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceReplaced {
+			nft_id: NFT_ID_0,
+			resource_id: 0,
+		}));
+		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().resource, basic_resource("level-1"));
+		assert_eq!(RMRKCore::pending_resource_replacements((0, 0, 0)), None);
+		// BOB accepts the resource and gives it a priority
+		assert_ok!(RMRKCore::accept_resource(Origin::signed(BOB), 0, 0, 0));
+		assert_ok!(RMRKCore::set_priority(Origin::signed(BOB), 0, 0, bvec![0]));
+		// BOB cannot replace resources
+		assert_noop!(
+			RMRKCore::replace_resource(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				0,
+				composable_resource.clone()
+			),
+			Error::<Test>::NoPermission
+		);
+		// ALICE replaces the accepted resource, pending for BOB
+		assert_ok!(RMRKCore::replace_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			0,
+			composable_resource.clone()
+		));
+		// The current resource is unchanged until BOB accepts
+		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().resource, basic_resource("level-1"));
+		assert_eq!(RMRKCore::equippable_bases((0, 0, 0)), None);
+		// ALICE cannot accept the replacement for BOB
+		assert_noop!(
+			RMRKCore::accept_resource_replacement(Origin::signed(ALICE), 0, 0, 0),
+			Error::<Test>::NoPermission
+		);
+		// BOB accepts the replacement in a single step
+		assert_ok!(RMRKCore::accept_resource_replacement(Origin::signed(BOB), 0, 0, 0));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceReplacementAccepted {
+			nft_id: NFT_ID_0,
+			resource_id: 0,
+		}));
+		// The resource keeps its ID, acceptance and priority, with the new equip indexes
+		let resource = RMRKCore::resources((0, 0, 0)).unwrap();
+		assert_eq!(resource.resource, composable_resource);
+		assert!(!resource.pending);
+		assert_eq!(RMRKCore::priorities((0, 0, 0)), Some(0));
+		assert_eq!(RMRKCore::equippable_bases((0, 0, 0)), Some(1));
+		assert!(RMRKCore::equippable_slots((0, 0, 0, 0, 1)).is_some());
+		// Accepting again fails
+		assert_noop!(
+			RMRKCore::accept_resource_replacement(Origin::signed(BOB), 0, 0, 0),
+			Error::<Test>::ResourceNotPending
+		);
+		// BOB can reject a replacement, keeping the current resource
+		assert_ok!(RMRKCore::replace_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			0,
+			basic_resource("level-0")
+		));
+		assert_ok!(RMRKCore::reject_resource(Origin::signed(BOB), 0, 0, 0));
+		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().resource, composable_resource);
+		assert_eq!(RMRKCore::pending_resource_replacements((0, 0, 0)), None);
+	});
+}
+
+/// Resource: Basic resource removal
+#[test]
+fn resource_removal_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT
+		assert_ok!(basic_mint());
+
+		let basic_resource =
+			BasicResource { src: None, metadata: None, license: None, thumb: None };
+
+		// Add resource to NFT
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			basic_resource,
+		));
+		// Resource res-1 doesn't exist
+		assert_noop!(
+			RMRKCore::remove_resource(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				1, // resource_id
+			),
+			Error::<Test>::ResourceDoesntExist
+		);
+		// Only collection issuer can request resource removal
+		assert_noop!(
+			RMRKCore::remove_resource(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				0, // resource_id
+			),
+			Error::<Test>::NoPermission
+		);
+		// Remove resource
+		assert_ok!(RMRKCore::remove_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			0, // resource_id
+		));
+		// Successful resource removal should trigger ResourceRemoval event
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceRemoval {
+			nft_id: 0,
+			resource_id: 0, // resource_id
+		}));
+		// Since ALICE rootowns NFT, resource should be removed
+		assert_eq!(RMRKCore::resources((0, 0, 0)), None);
+	});
+}
+
+/// Resource: Removal cleans up equippable bases, slots and priorities
+#[test]
+fn resource_removal_cleans_indexes_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT
+		assert_ok!(basic_mint());
+		let composable_resource = ComposableResource {
+			parts: bvec![0, 1],
+			src: None,
+			base: 0,
+			license: None,
+			metadata: None,
+			slot: Some((0, 1)),
+			thumb: None,
+		};
+		// Add two composable resources sharing base 0
+		assert_ok!(RMRKCore::add_composable_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			composable_resource.clone()
+		));
+		assert_ok!(RMRKCore::add_composable_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			composable_resource
+		));
+		assert_eq!(RMRKCore::equippable_bases((0, 0, 0)), Some(2));
+		// Set priorities for both resources
+		assert_ok!(RMRKCore::set_priority(Origin::signed(ALICE), 0, 0, bvec![1, 0]));
+		// Remove the first resource
+		assert_ok!(RMRKCore::remove_resource(Origin::signed(ALICE), 0, 0, 0));
+		// The base is still used by the second resource
+		assert_eq!(RMRKCore::equippable_bases((0, 0, 0)), Some(1));
+		// The slot and priority of the removed resource are gone
+		assert_eq!(RMRKCore::equippable_slots((0, 0, 0, 0, 1)), None);
+		assert!(RMRKCore::equippable_slots((0, 0, 1, 0, 1)).is_some());
+		assert_eq!(RMRKCore::priorities((0, 0, 0)), None);
+		assert_eq!(RMRKCore::priorities((0, 0, 1)), Some(0));
+		// Removing the second resource releases the base
+		assert_ok!(RMRKCore::remove_resource(Origin::signed(ALICE), 0, 0, 1));
+		assert_eq!(RMRKCore::equippable_bases((0, 0, 0)), None);
+		assert_eq!(RMRKCore::equippable_slots((0, 0, 1, 0, 1)), None);
+		assert_eq!(RMRKCore::priorities((0, 0, 1)), None);
+	});
+}
+
+/// Resource: Resource removal with pending and accept
+#[test]
+fn resource_removal_pending_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(BOB),
+			COLLECTION_ID_0,
+			royalty(BOB, Permill::from_float(1.525)),
+			bvec![0u8; 20],
+			true,
+			None
+		));
+
+		let basic_resource =
+			BasicResource { src: None, metadata: None, license: None, thumb: None };
+
+		// Add resource to NFT
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			basic_resource,
+		));
+
+		assert_ok!(RMRKCore::accept_resource(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 0,));
+		// Accepting a resource removal that is not pending should fail
+		assert_noop!(
+			RMRKCore::accept_resource_removal(Origin::signed(BOB), 0, 0, 0),
+			Error::<Test>::ResourceNotPending
+		);
+		// Only collection's issuer can request resource removal
+		assert_noop!(
+			RMRKCore::remove_resource(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				0, // resource_id
+			),
+			Error::<Test>::NoPermission
+		);
+		// Resource removal requested by the collection issuer
+		assert_ok!(RMRKCore::remove_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			0, // resource_id
+		));
+		// Since ALICE doesn't root-own NFT, resource's removal is waiting for acceptance
+		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().pending_removal, true);
+		// ALICE doesn't own BOB's NFT, so accept should fail
+		assert_noop!(
+			RMRKCore::accept_resource_removal(Origin::signed(ALICE), 0, 0, 0),
+			Error::<Test>::NoPermission
+		);
+		// Resource res-1 doesn't exist
+		assert_noop!(
+			RMRKCore::accept_resource_removal(Origin::signed(BOB), 0, 0, 1),
+			Error::<Test>::ResourceDoesntExist
+		);
+		// BOB can accept his own NFT's pending resource removal
+		assert_ok!(RMRKCore::accept_resource_removal(Origin::signed(BOB), 0, 0, 0));
+		// Successful resource removal acceptance should trigger ResourceRemovalAccepted event
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceRemovalAccepted {
+			nft_id: 0,
+			resource_id: 0, // resource_id
+		}));
+		// Resource removed
+		assert_eq!(RMRKCore::resources((0, 0, 0)), None);
+	});
+}
+
+/// Resource: Collection-level shared resources with per-NFT opt-out
+#[test]
+fn collection_resources_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// Mint NFT 0 to ALICE and NFT 1 to BOB
+		assert_ok!(basic_mint());
+		assert_ok!(RMRKCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(BOB),
+			COLLECTION_ID_0,
+			None,
+			bvec![0u8; 20],
+			true,
+			None
+		));
+		let reserved = Balances::reserved_balance(ALICE);
+
+		let shared = ResourceTypes::Basic(BasicResource {
+			src: Some(stbd("shared-src")),
+			metadata: None,
+			license: None,
+			thumb: None,
+		});
+
+		// BOB isn't the collection issuer, so he can't add shared resources
+		assert_noop!(
+			RMRKCore::add_collection_resource(Origin::signed(BOB), COLLECTION_ID_0, shared.clone()),
+			Error::<Test>::NoPermission
+		);
+		// ALICE adds a shared resource to the collection
+		assert_ok!(RMRKCore::add_collection_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			shared.clone()
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CollectionResourceAdded {
+			collection_id: COLLECTION_ID_0,
+			resource_id: 0,
+		}));
+		// A single deposit is reserved, however many NFTs share the resource
+		assert_eq!(Balances::reserved_balance(ALICE), reserved + ResourceDeposit::get());
+
+		// ALICE adds a resource of its own to NFT 0
+		let own = BasicResource {
+			src: Some(stbd("own-src")),
+			metadata: None,
+			license: None,
+			thumb: None,
+		};
+		assert_ok!(RMRKCore::add_basic_resource(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			own.clone()
+		));
+		// NFT 0 displays its own resource followed by the shared one
+		assert_eq!(
+			RMRKCore::nft_effective_resources(COLLECTION_ID_0, NFT_ID_0),
+			vec![
+				EffectiveResource::Nft(ResourceInfo {
+					id: 0,
+					resource: ResourceTypes::Basic(own),
+					pending: false,
+					pending_removal: false,
+				}),
+				EffectiveResource::Collection { id: 0, resource: shared.clone() },
+			]
+		);
+		// NFT 1 displays the shared resource without accepting it
+		assert_eq!(
+			RMRKCore::nft_effective_resources(COLLECTION_ID_0, 1),
+			vec![EffectiveResource::Collection { id: 0, resource: shared.clone() }]
+		);
+
+		// Only the root owner can opt an NFT out of a shared resource
+		assert_noop!(
+			RMRKCore::set_collection_resource_opt_out(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				1,
+				0,
+				true
+			),
+			Error::<Test>::NoPermission
+		);
+		// Opting out of a nonexistent shared resource fails
+		assert_noop!(
+			RMRKCore::set_collection_resource_opt_out(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				1,
+				1,
+				true
+			),
+			Error::<Test>::ResourceDoesntExist
+		);
+		// BOB opts NFT 1 out of the shared resource
+		assert_ok!(RMRKCore::set_collection_resource_opt_out(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
 			1,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
-		));
-		// ALICE sends NFT (0, 2) to NFT (0, 0)
-		assert_ok!(RMRKCore::send(
-			Origin::signed(ALICE),
 			0,
-			2,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			true
 		));
-		// NFT (0, 0) is parent of NFT (0, 1)
-		assert!(RMRKCore::children((0, 0), (0, 1)).is_some());
-		assert!(RMRKCore::children((0, 0), (0, 2)).is_some());
-		// ALICE sends NFT (0, 1) to NFT (0, 2)
-		assert_ok!(RMRKCore::send(
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CollectionResourceOptOutSet {
+			collection_id: COLLECTION_ID_0,
+			nft_id: 1,
+			resource_id: 0,
+			opt_out: true,
+		}));
+		assert!(RMRKCore::nft_effective_resources(COLLECTION_ID_0, 1).is_empty());
+		// NFT 0 still displays the shared resource
+		assert_eq!(RMRKCore::nft_effective_resources(COLLECTION_ID_0, NFT_ID_0).len(), 2);
+
+		// Removing the shared resource removes the opt-outs and releases the deposit
+		assert_ok!(RMRKCore::remove_collection_resource(Origin::signed(ALICE), COLLECTION_ID_0, 0));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::CollectionResourceRemoved {
+			collection_id: COLLECTION_ID_0,
+			resource_id: 0,
+		}));
+		assert!(RMRKCore::collection_resource_opt_outs((COLLECTION_ID_0, 0, 1)).is_none());
+		assert_eq!(RMRKCore::nft_effective_resources(COLLECTION_ID_0, NFT_ID_0).len(), 1);
+		assert_eq!(
+			Balances::reserved_balance(ALICE),
+			reserved + ResourceDeposit::get() // own resource of NFT 0
+		);
+		// Removing it again fails
+		assert_noop!(
+			RMRKCore::remove_collection_resource(Origin::signed(ALICE), COLLECTION_ID_0, 0),
+			Error::<Test>::ResourceDoesntExist
+		);
+
+		// Shared resource IDs are not reused, and burning an NFT removes its opt-outs
+		assert_ok!(RMRKCore::add_collection_resource(
 			Origin::signed(ALICE),
-			0,
+			COLLECTION_ID_0,
+			shared
+		));
+		assert_ok!(RMRKCore::set_collection_resource_opt_out(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
 			1,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+			1,
+			true
 		));
-		// NFT (0, 0) is no longer parent of NFT (0, 1)
-		assert!(RMRKCore::children((0, 0), (0, 1)).is_none());
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(BOB), COLLECTION_ID_0, 1, MAX_BURNS));
+		assert!(RMRKCore::collection_resource_opt_outs((COLLECTION_ID_0, 1, 1)).is_none());
 	});
 }
 
-/// NFT: Send tests, multi-generational circular testing (RMRK2.0 spec: SEND)
+/// Property: Setting property tests (RMRK2.0 spec: SETPROPERTY)
 #[test]
-fn send_to_grandchild_fails() {
+fn set_property_works() {
 	ExtBuilder::default().build().execute_with(|| {
+		// Define property key
+		let key = stbk("test-key");
+		// Define property value
+		let value = stb("test-value");
+		// set_property fails without a collection (CollectionUnknown)
+		assert_noop!(
+			RMRKCore::set_property(Origin::signed(ALICE), 0, Some(0), key.clone(), value.clone()),
+			Error::<Test>::CollectionUnknown
+		);
 		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint NFTs (0, 0), (0, 1), (0, 2)
-		for _ in 0..3 {
-			assert_ok!(basic_mint());
-		}
-		// Alice sends NFT (0, 1) to NFT (0, 0)
-		assert_ok!(RMRKCore::send(
-			Origin::signed(ALICE),
-			0,
-			1,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
-		));
-		// Alice sends NFT (0, 2) to NFT (0, 1)
-		assert_ok!(RMRKCore::send(
+		// Mint NFT
+		assert_ok!(basic_mint());
+		// ALICE sets property on NFT
+		assert_ok!(RMRKCore::set_property(
 			Origin::signed(ALICE),
 			0,
-			2,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 1),
+			Some(0),
+			key.clone(),
+			value.clone()
 		));
-		// Sending NFT to its own grandchild should fail
+		// Successful property setting should trigger a PropertySet event
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PropertySet {
+			collection_id: 0,
+			maybe_nft_id: Some(0),
+			key: key.clone(),
+			value: value.clone(),
+		}));
+		// Property value now exists
+		assert_eq!(RMRKCore::properties((0, Some(0), key.clone())).unwrap(), value.clone());
+		// BOB does not own NFT so attempt to set property should fail
 		assert_noop!(
-			RMRKCore::send(
-				Origin::signed(ALICE),
-				0,
-				0,
-				AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
-			),
-			Error::<Test>::CannotSendToDescendentOrSelf
+			RMRKCore::set_property(Origin::signed(BOB), 0, Some(0), key.clone(), value.clone()),
+			Error::<Test>::NoPermission
 		);
 	});
 }
 
-/// NFT: Burn simple tests (RMRK2.0 spec: BURN)
+/// Property: Removing property tests
 #[test]
-fn burn_nft_works() {
+fn remove_property_works() {
 	ExtBuilder::default().build().execute_with(|| {
 		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint an NFT
+		// Mint NFT
 		assert_ok!(basic_mint());
-		// Add two resources to NFT (to test if burning also burns the resources)
-
-		let basic_resource =
-			BasicResource { src: None, metadata: None, license: None, thumb: None };
-
-		assert_ok!(RMRKCore::add_basic_resource(
+		let reserved = Balances::reserved_balance(ALICE);
+		// Removing a property that is not set fails
+		assert_noop!(
+			RMRKCore::remove_property(Origin::signed(ALICE), 0, Some(0), stbk("key")),
+			Error::<Test>::PropertyDoesntExist
+		);
+		// ALICE sets property on NFT
+		assert_ok!(RMRKCore::set_property(
 			Origin::signed(ALICE),
 			0,
-			0,
-			basic_resource.clone(),
+			Some(0),
+			stbk("key"),
+			stb("value")
 		));
-
-		assert_ok!(RMRKCore::add_basic_resource(Origin::signed(ALICE), 0, 0, basic_resource,));
-
-		// Ensure resources are there
-		assert_eq!(Resources::<Test>::iter_prefix((COLLECTION_ID_0, NFT_ID_0)).count(), 2);
-
-		// BOB should not be able to burn ALICE's NFT
+		// BOB cannot remove the property
 		assert_noop!(
-			RMRKCore::burn_nft(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, MAX_BURNS),
+			RMRKCore::remove_property(Origin::signed(BOB), 0, Some(0), stbk("key")),
 			Error::<Test>::NoPermission
 		);
-		// ALICE burns her NFT
-		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, MAX_BURNS));
-		// Successful burn creates NFTBurned event
-		System::assert_last_event(MockEvent::RmrkCore(crate::Event::NFTBurned {
-			owner: ALICE,
-			nft_id: 0,
+		// ALICE removes the property
+		assert_ok!(RMRKCore::remove_property(Origin::signed(ALICE), 0, Some(0), stbk("key")));
+		// Successful removal should trigger a PropertyRemoved event
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PropertyRemoved {
+			collection_id: 0,
+			maybe_nft_id: Some(0),
+			key: stbk("key"),
 		}));
-		// NFT count of collection is now 0
-		assert_eq!(RMRKCore::collections(COLLECTION_ID_0).unwrap().nfts_count, 0);
-		// ALICE can't burn an NFT twice
-		assert_noop!(
-			RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, MAX_BURNS),
-			Error::<Test>::NoAvailableNftId
-		);
-		// Burned NFT no longer exists
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, NFT_ID_0).is_none(), true);
-		// Resources associated with the NFT should no longer exist
-		assert_eq!(Resources::<Test>::iter_prefix((COLLECTION_ID_0, NFT_ID_0)).count(), 0);
+		// Property is gone and its deposit is released
+		assert_eq!(RMRKCore::properties((0, Some(0), stbk("key"))), None);
+		assert_eq!(Balances::reserved_balance(ALICE), reserved);
 	});
 }
 
-/// NFT: Burn complex multi-generational tests (RMRK2.0 spec: BURN)
+/// Property: Setting several properties at once
 #[test]
-fn burn_nft_with_great_grandchildren_works() {
+fn set_properties_works() {
 	ExtBuilder::default().build().execute_with(|| {
 		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint NFTs (0, 0), (0, 1), (0, 2), (0, 3)
-		for _ in 0..4 {
-			assert_ok!(basic_mint());
-		}
-
-		let basic_resource =
-			BasicResource { src: None, metadata: None, license: None, thumb: None };
-
-		// Add two resources to the great-grandchild (0, 3)
-		assert_ok!(RMRKCore::add_basic_resource(
-			Origin::signed(ALICE),
-			COLLECTION_ID_0,
-			3,
-			basic_resource.clone(),
-		));
-
-		assert_ok!(RMRKCore::add_basic_resource(
+		// ALICE sets two collection properties at once
+		assert_ok!(RMRKCore::set_properties(
 			Origin::signed(ALICE),
-			COLLECTION_ID_0,
-			3,
-			basic_resource,
+			0,
+			None,
+			bvec![(stbk("key-1"), stb("value-1")), (stbk("key-2"), stb("value-2"))],
 		));
+		// Both properties are set
+		assert_eq!(RMRKCore::properties((0, None, stbk("key-1"))), Some(stb("value-1")));
+		assert_eq!(RMRKCore::properties((0, None, stbk("key-2"))), Some(stb("value-2")));
+		// BOB cannot set collection properties
+		assert_noop!(
+			RMRKCore::set_properties(
+				Origin::signed(BOB),
+				0,
+				None,
+				bvec![(stbk("key-3"), stb("value-3"))],
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
 
-		// Ensure resources are there
-		assert_eq!(Resources::<Test>::iter_prefix((COLLECTION_ID_0, 3)).count(), 2);
-
-		// ALICE sends NFT (0, 1) to NFT (0, 0)
-		assert_ok!(RMRKCore::send(
+/// Property: NFTs hold at most MaxPropertiesPerNft properties and MaxResourcesPerNft resources
+#[test]
+fn properties_and_resources_per_nft_are_bounded() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a basic collection and mint NFT (0, 0)
+		assert_ok!(basic_collection());
+		assert_ok!(basic_mint());
+		let basic_resource =
+			BasicResource { src: None, metadata: None, license: None, thumb: None };
+		// ALICE fills NFT (0, 0) with properties, owner properties and resources, and the
+		// collection with shared resources
+		for i in 0..MaxPropertiesPerNft::get() {
+			let key = stbk(&format!("key-{}", i));
+			assert_ok!(RMRKCore::set_property(
+				Origin::signed(ALICE),
+				0,
+				Some(0),
+				key.clone(),
+				stb("value")
+			));
+			assert_ok!(RMRKCore::set_owner_property(
+				Origin::signed(ALICE),
+				0,
+				0,
+				key,
+				stb("value")
+			));
+		}
+		for _ in 0..MaxResourcesPerNft::get() {
+			assert_ok!(RMRKCore::add_basic_resource(
+				Origin::signed(ALICE),
+				0,
+				0,
+				basic_resource.clone()
+			));
+			assert_ok!(RMRKCore::add_collection_resource(
+				Origin::signed(ALICE),
+				0,
+				ResourceTypes::Basic(basic_resource.clone()),
+			));
+		}
+		// No new key nor resource fits anymore
+		assert_noop!(
+			RMRKCore::set_property(Origin::signed(ALICE), 0, Some(0), stbk("new"), stb("value")),
+			Error::<Test>::TooManyProperties
+		);
+		assert_noop!(
+			RMRKCore::set_owner_property(Origin::signed(ALICE), 0, 0, stbk("new"), stb("value")),
+			Error::<Test>::TooManyProperties
+		);
+		assert_noop!(
+			RMRKCore::add_basic_resource(Origin::signed(ALICE), 0, 0, basic_resource.clone()),
+			Error::<Test>::TooManyResources
+		);
+		assert_noop!(
+			RMRKCore::add_collection_resource(
+				Origin::signed(ALICE),
+				0,
+				ResourceTypes::Basic(basic_resource)
+			),
+			Error::<Test>::TooManyResources
+		);
+		// Existing keys can still be overwritten, and collection properties are not bounded
+		assert_ok!(RMRKCore::set_property(
 			Origin::signed(ALICE),
 			0,
-			1,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			Some(0),
+			stbk("key-0"),
+			stb("other")
 		));
-		// ALICE sends NFT (0, 2) to NFT (0, 1)
-		assert_ok!(RMRKCore::send(
+		assert_ok!(RMRKCore::set_owner_property(
 			Origin::signed(ALICE),
 			0,
-			2,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 1),
+			0,
+			stbk("key-0"),
+			stb("other")
 		));
-		// ALICE sends NFT (0, 3) to NFT (0, 2)
-		assert_ok!(RMRKCore::send(
+		assert_ok!(RMRKCore::set_property(
 			Origin::signed(ALICE),
 			0,
-			3,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+			None,
+			stbk("new"),
+			stb("value")
 		));
-		// Great-grandchild NFT (0, 3) exists
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 3).is_some(), true);
-		// Burn great-grandparent NFT (0, 0)
-		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, MAX_BURNS));
-		// Great-grandchild NFT (0, 3) is dead :'-(
-		assert!(RMRKCore::nfts(COLLECTION_ID_0, 3).is_none());
-		// Great-grandchild resources are gone
-		assert_eq!(Resources::<Test>::iter_prefix((COLLECTION_ID_0, 3)).count(), 0);
+		// Burning the NFT removes them all
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), 0, 0, MAX_BURNS));
+		assert_no_orphaned_keys();
 	});
 }
 
-/// NFT: Burn beyond max_recursions fails gracefully
+/// Property: Per-key permissions decide who may write NFT properties
 #[test]
-fn burn_nft_beyond_max_recursions_fails_gracefully() {
+fn property_permissions_work() {
 	ExtBuilder::default().build().execute_with(|| {
 		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint NFTs (0, 0), (0, 1), (0, 2), (0, 3)
-		for _ in 0..5 {
-			assert_ok!(basic_mint());
-		}
-		// ALICE sends NFT (0, 1) to NFT (0, 0)
+		// Mint NFT and send it to BOB
+		assert_ok!(basic_mint());
 		assert_ok!(RMRKCore::send(
 			Origin::signed(ALICE),
 			0,
-			1,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
+		));
+		// By default neither BOB nor ALICE may write properties on BOB's NFT
+		assert_noop!(
+			RMRKCore::set_property(Origin::signed(BOB), 0, Some(0), stbk("mood"), stb("happy")),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			RMRKCore::set_property(Origin::signed(ALICE), 0, Some(0), stbk("mood"), stb("happy")),
+			Error::<Test>::NoPermission
+		);
+		// Only the issuer can configure permissions
+		assert_noop!(
+			RMRKCore::set_property_permission(
+				Origin::signed(BOB),
+				0,
+				stbk("mood"),
+				PropertyPermission::OwnerOnly
+			),
+			Error::<Test>::NoPermission
+		);
+		// ALICE lets owners write the "mood" key
+		assert_ok!(RMRKCore::set_property_permission(
+			Origin::signed(ALICE),
+			0,
+			stbk("mood"),
+			PropertyPermission::OwnerOnly
 		));
-		// ALICE sends NFT (0, 2) to NFT (0, 1)
-		assert_ok!(RMRKCore::send(
-			Origin::signed(ALICE),
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PropertyPermissionSet {
+			collection_id: 0,
+			key: stbk("mood"),
+			permission: PropertyPermission::OwnerOnly,
+		}));
+		// BOB can now write the key, ALICE cannot
+		assert_ok!(RMRKCore::set_property(
+			Origin::signed(BOB),
 			0,
-			2,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 1),
+			Some(0),
+			stbk("mood"),
+			stb("happy")
 		));
-		// ALICE sends NFT (0, 3) to NFT (0, 2)
-		assert_ok!(RMRKCore::send(
+		assert_noop!(
+			RMRKCore::set_property(Origin::signed(ALICE), 0, Some(0), stbk("mood"), stb("sad")),
+			Error::<Test>::NoPermission
+		);
+		// ALICE makes the "origin" key immutable and sets it while still owning an NFT
+		assert_ok!(basic_mint());
+		assert_ok!(RMRKCore::set_property_permission(
 			Origin::signed(ALICE),
 			0,
-			3,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+			stbk("origin"),
+			PropertyPermission::Immutable
 		));
-		// ALICE sends NFT (0, 4) to NFT (0, 3)
-		assert_ok!(RMRKCore::send(
+		assert_ok!(RMRKCore::set_property(
 			Origin::signed(ALICE),
 			0,
-			4,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 3),
+			Some(1),
+			stbk("origin"),
+			stb("genesis")
 		));
-		// All NFTs exist
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 0).is_some(), true);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 1).is_some(), true);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 2).is_some(), true);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 3).is_some(), true);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 4).is_some(), true);
-		// Burn great-grandparent NFT (0, 0)
+		// Immutable properties can neither be updated nor removed
 		assert_noop!(
-			RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, MAX_BURNS),
-			Error::<Test>::TooManyRecursions
+			RMRKCore::set_property(Origin::signed(ALICE), 0, Some(1), stbk("origin"), stb("x")),
+			Error::<Test>::PropertyImmutable
+		);
+		assert_noop!(
+			RMRKCore::remove_property(Origin::signed(ALICE), 0, Some(1), stbk("origin")),
+			Error::<Test>::PropertyImmutable
+		);
+		// An immutable key cannot be reconfigured
+		assert_noop!(
+			RMRKCore::set_property_permission(
+				Origin::signed(ALICE),
+				0,
+				stbk("origin"),
+				PropertyPermission::IssuerOnly
+			),
+			Error::<Test>::PropertyImmutable
 		);
-		// All NFTs still exist
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 0).is_some(), true);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 1).is_some(), true);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 2).is_some(), true);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 3).is_some(), true);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 4).is_some(), true);
 	});
 }
 
-/// NFT: Burn child removes NFT from owner-NFT's Children list
+/// Property: Root owners write their own properties, separate from the issuer's
 #[test]
-fn burn_child_nft_removes_parents_children() {
+fn owner_properties_work() {
 	ExtBuilder::default().build().execute_with(|| {
 		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint NFTs (0, 0), (0, 1), (0, 2), (0, 3)
-		for _ in 0..2 {
-			assert_ok!(basic_mint());
-		}
-		// ALICE sends NFT (0, 1) to NFT (0, 0)
+		// Mint NFT and send it to BOB
+		assert_ok!(basic_mint());
 		assert_ok!(RMRKCore::send(
 			Origin::signed(ALICE),
-			0,
-			1,
-			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB),
 		));
-		// All NFTs exist
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 0).is_some(), true);
-		assert_eq!(RMRKCore::nfts(COLLECTION_ID_0, 1).is_some(), true);
-		// NFT (0, 0) should have 1 Children storage member
-		assert_eq!(Children::<Test>::iter_prefix((0, 0)).count(), 1);
-		// Burn NFT (0, 1)
-		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), 0, 1, MAX_BURNS),);
-		// NFT (0, 0) should have 0 Children storage members
-		assert_eq!(Children::<Test>::iter_prefix((0, 0)).count(), 0);
-	});
-}
-
-/// Resource: Basic resource addition (RMRK2.0 spec: RESADD)
-#[test]
-fn create_resource_works() {
-	ExtBuilder::default().build().execute_with(|| {
-		let basic_resource =
-			BasicResource { src: None, metadata: None, license: None, thumb: None };
-
-		// Adding a resource to non-existent NFT should fail
+		let bob_reserved = Balances::reserved_balance(BOB);
+		// ALICE is not the owner and cannot write owner properties
 		assert_noop!(
-			RMRKCore::add_basic_resource(
+			RMRKCore::set_owner_property(
 				Origin::signed(ALICE),
-				0, // collection_id
-				0, // nft_id
-				basic_resource,
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				stbk("nickname"),
+				stb("alice")
 			),
-			Error::<Test>::CollectionUnknown
+			Error::<Test>::NoPermission
 		);
-		// Create a basic collection
-		assert_ok!(basic_collection());
-		// Mint NFT
-		assert_ok!(basic_mint());
-
-		let basic_resource =
-			BasicResource { src: None, metadata: None, license: None, thumb: None };
-
-		// Add resource to NFT
-		assert_ok!(RMRKCore::add_basic_resource(
-			Origin::signed(ALICE),
+		// BOB sets an owner property
+		assert_ok!(RMRKCore::set_owner_property(
+			Origin::signed(BOB),
 			COLLECTION_ID_0,
 			NFT_ID_0,
-			basic_resource,
+			stbk("nickname"),
+			stb("bob")
 		));
-		// Successful resource addition should trigger ResourceAdded event
-		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceAdded {
-			nft_id: 0,
-			resource_id: 0, // resource_id
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::OwnerPropertySet {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			key: stbk("nickname"),
+			value: stb("bob"),
 		}));
-		// Since ALICE rootowns NFT, pending status of resource should be false
-		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().pending, false);
-
-		// Create Composable resource
-		let composable_resource = ComposableResource {
-			parts: vec![0, 1].try_into().unwrap(), // BoundedVec of Parts
-			src: Some(stbd("composable-resource")),
-			base: 0, // BaseID
-			license: None,
-			metadata: None,
-			slot: None,
-			thumb: None,
-		};
-
-		// Composable resource addition works
-		assert_ok!(RMRKCore::add_composable_resource(
-			Origin::signed(ALICE),
+		// The owner property does not touch the issuer's properties
+		assert_eq!(
+			RMRKCore::owner_properties((COLLECTION_ID_0, NFT_ID_0, stbk("nickname"))),
+			Some(stb("bob"))
+		);
+		assert_eq!(RMRKCore::properties((COLLECTION_ID_0, Some(NFT_ID_0), stbk("nickname"))), None);
+		// BOB reserved a deposit for 8 key bytes and 3 value bytes
+		assert_eq!(
+			Balances::reserved_balance(BOB),
+			bob_reserved + 11 * PropertyDepositPerByte::get()
+		);
+		// BOB sends the NFT to CHARLIE, who overwrites the property
+		assert_ok!(RMRKCore::send(
+			Origin::signed(BOB),
 			COLLECTION_ID_0,
 			NFT_ID_0,
-			composable_resource,
+			AccountIdOrCollectionNftTuple::AccountId(CHARLIE),
 		));
-
-		// Create Slot resource
-		let slot_resource = SlotResource {
-			src: Some(stbd("slot-resource")),
-			base: 0, // BaseID
-			license: None,
-			metadata: None,
-			slot: 0, // SlotID
-			thumb: None,
-		};
-
-		// Slot resource addition works
-		assert_ok!(RMRKCore::add_slot_resource(
-			Origin::signed(ALICE),
+		let charlie_reserved = Balances::reserved_balance(CHARLIE);
+		assert_ok!(RMRKCore::set_owner_property(
+			Origin::signed(CHARLIE),
 			COLLECTION_ID_0,
 			NFT_ID_0,
-			slot_resource,
+			stbk("nickname"),
+			stb("charlie")
+		));
+		// BOB's deposit is released and CHARLIE's is reserved
+		assert_eq!(Balances::reserved_balance(BOB), bob_reserved);
+		assert_eq!(
+			Balances::reserved_balance(CHARLIE),
+			charlie_reserved + 15 * PropertyDepositPerByte::get()
+		);
+		// CHARLIE removes the property and gets the deposit back
+		assert_ok!(RMRKCore::remove_owner_property(
+			Origin::signed(CHARLIE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			stbk("nickname")
+		));
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::OwnerPropertyRemoved {
+			collection_id: COLLECTION_ID_0,
+			nft_id: NFT_ID_0,
+			key: stbk("nickname"),
+		}));
+		assert_eq!(RMRKCore::owner_properties((COLLECTION_ID_0, NFT_ID_0, stbk("nickname"))), None);
+		assert_eq!(Balances::reserved_balance(CHARLIE), charlie_reserved);
+		// Removing it again fails
+		assert_noop!(
+			RMRKCore::remove_owner_property(
+				Origin::signed(CHARLIE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				stbk("nickname")
+			),
+			Error::<Test>::PropertyDoesntExist
+		);
+		// Burning the NFT removes owner properties and releases their deposits
+		assert_ok!(RMRKCore::set_owner_property(
+			Origin::signed(CHARLIE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			stbk("level"),
+			stb("9")
 		));
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(CHARLIE), COLLECTION_ID_0, NFT_ID_0, 1));
+		assert_eq!(RMRKCore::owner_properties((COLLECTION_ID_0, NFT_ID_0, stbk("level"))), None);
+		assert_eq!(Balances::reserved_balance(CHARLIE), charlie_reserved);
 	});
 }
 
-/// Minting with resources works
+/// Property: Values must match the type declared in the collection schema
 #[test]
-fn add_resource_on_mint_works() {
+fn property_schema_works() {
 	ExtBuilder::default().build().execute_with(|| {
-		let basic_resource: BasicResource<BoundedVec<u8, UniquesStringLimit>> =
-			BasicResource { src: None, metadata: None, license: None, thumb: None };
-
 		// Create a basic collection
 		assert_ok!(basic_collection());
-
-		let basic_resource =
-			BasicResource { src: None, metadata: None, license: None, thumb: None };
-
-		// Resources to add
-		let resources_to_add = bvec![
-			ResourceTypes::Basic(basic_resource.clone()),
-			ResourceTypes::Basic(basic_resource),
-		];
-
 		// Mint NFT
-		assert_ok!(RMRKCore::mint_nft(
+		assert_ok!(basic_mint());
+		// Only the issuer can declare the schema
+		assert_noop!(
+			RMRKCore::set_property_schema(
+				Origin::signed(BOB),
+				COLLECTION_ID_0,
+				stbk("level"),
+				Some(PropertyType::U32)
+			),
+			Error::<Test>::NoPermission
+		);
+		// ALICE declares "level" as u32 and "active" as bool
+		assert_ok!(RMRKCore::set_property_schema(
 			Origin::signed(ALICE),
-			Some(ALICE),
 			COLLECTION_ID_0,
-			Some(ALICE),
-			Some(Permill::from_float(1.525)),
-			bvec![0u8; 20],
-			true,
-			Some(resources_to_add),
+			stbk("level"),
+			Some(PropertyType::U32)
 		));
-
-		assert_eq!(RMRKCore::resources((0, 0, 0)).is_some(), true);
-		assert_eq!(RMRKCore::resources((0, 0, 1)).is_some(), true);
-	});
-}
-
-/// Minting with more than max resources (set to 3 in mock) should panic
-#[should_panic]
-#[test]
-fn add_resource_on_mint_beyond_max_fails() {
-	ExtBuilder::default().build().execute_with(|| {
-		// Create a basic collection
-		assert_ok!(basic_collection());
-
-		let basic_resource =
-			BasicResource { src: None, metadata: None, license: None, thumb: None };
-
-		// Resources to add
-		let resources_to_add = bvec![
-			ResourceTypes::Basic(basic_resource.clone()),
-			ResourceTypes::Basic(basic_resource.clone()),
-			ResourceTypes::Basic(basic_resource.clone()),
-			ResourceTypes::Basic(basic_resource),
-		];
-
-		// Mint NFT
-		assert_ok!(RMRKCore::mint_nft(
+		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PropertySchemaSet {
+			collection_id: COLLECTION_ID_0,
+			key: stbk("level"),
+			property_type: Some(PropertyType::U32),
+		}));
+		assert_ok!(RMRKCore::set_property_schema(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			stbk("active"),
+			Some(PropertyType::Bool)
+		));
+		// The schema is readable as a whole
+		let schema = RMRKCore::collection_property_schema(COLLECTION_ID_0);
+		assert_eq!(schema.len(), 2);
+		assert!(schema.contains(&(b"active".to_vec(), PropertyType::Bool)));
+		assert!(schema.contains(&(b"level".to_vec(), PropertyType::U32)));
+		// A SCALE encoded u32 is accepted
+		let level: ValueLimitOf<Test> = 7u32.encode().try_into().unwrap();
+		assert_ok!(RMRKCore::set_property(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			Some(NFT_ID_0),
+			stbk("level"),
+			level
+		));
+		// Malformed values are rejected
+		assert_noop!(
+			RMRKCore::set_property(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				Some(NFT_ID_0),
+				stbk("level"),
+				stb("seven")
+			),
+			Error::<Test>::PropertyTypeMismatch
+		);
+		assert_noop!(
+			RMRKCore::set_property(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				Some(NFT_ID_0),
+				stbk("active"),
+				bvec![2u8]
+			),
+			Error::<Test>::PropertyTypeMismatch
+		);
+		// Owner properties follow the schema too
+		assert_noop!(
+			RMRKCore::set_owner_property(
+				Origin::signed(ALICE),
+				COLLECTION_ID_0,
+				NFT_ID_0,
+				stbk("active"),
+				stb("yes")
+			),
+			Error::<Test>::PropertyTypeMismatch
+		);
+		assert_ok!(RMRKCore::set_owner_property(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			stbk("active"),
+			bvec![1u8]
+		));
+		// Clearing the type accepts any value again
+		assert_ok!(RMRKCore::set_property_schema(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			stbk("level"),
+			None
+		));
+		assert_ok!(RMRKCore::set_property(
 			Origin::signed(ALICE),
-			Some(ALICE),
 			COLLECTION_ID_0,
-			Some(ALICE),
-			Some(Permill::from_float(1.525)),
-			bvec![0u8; 20],
-			true,
-			Some(resources_to_add),
+			Some(NFT_ID_0),
+			stbk("level"),
+			stb("seven")
 		));
 	});
 }
 
-/// Resource: Resource addition with pending and accept (RMRK2.0 spec: ACCEPT)
+/// Deposit: Resources reserve a deposit from the issuer, released on removal
 #[test]
-fn add_resource_pending_works() {
+fn resource_deposit_works() {
 	ExtBuilder::default().build().execute_with(|| {
 		// Create a basic collection
 		assert_ok!(basic_collection());
 		// Mint NFT
-		assert_ok!(RMRKCore::mint_nft(
+		assert_ok!(basic_mint());
+		let reserved = Balances::reserved_balance(ALICE);
+		// Add resource to NFT
+		assert_ok!(RMRKCore::add_basic_resource(
 			Origin::signed(ALICE),
-			Some(BOB),
 			COLLECTION_ID_0,
-			Some(BOB),
-			Some(Permill::from_float(1.525)),
-			bvec![0u8; 20],
-			true,
-			None
+			NFT_ID_0,
+			BasicResource { src: None, metadata: None, license: None, thumb: None },
 		));
-
-		let basic_resource = BasicResource {
-			src: Some(stbd("res-src")),
-			metadata: None,
-			license: None,
-			thumb: None,
-		};
-
-		// Since BOB isn't collection issuer, he can't add resources
-		assert_noop!(
-			RMRKCore::add_basic_resource(
-				Origin::signed(BOB),
-				COLLECTION_ID_0,
-				NFT_ID_0,
-				basic_resource.clone(),
-			),
-			Error::<Test>::NoPermission
-		);
-
-		// Collection issuer can add resource
-		assert_ok!(RMRKCore::add_basic_resource(
+		// ALICE reserved the resource deposit
+		assert_eq!(Balances::reserved_balance(ALICE), reserved + ResourceDeposit::get());
+		assert_eq!(RMRKCore::collection_deposits(COLLECTION_ID_0), ResourceDeposit::get());
+		// Remove resource
+		assert_ok!(RMRKCore::remove_resource(
 			Origin::signed(ALICE),
 			COLLECTION_ID_0,
 			NFT_ID_0,
-			basic_resource
+			0, // resource_id
 		));
-
-		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().pending, true);
-		// ALICE doesn't own BOB's NFT, so accept should fail
-		assert_noop!(
-			RMRKCore::accept_resource(Origin::signed(ALICE), 0, 0, 0),
-			Error::<Test>::NoPermission
-		);
-		// BOB can accept his own NFT's pending resource
-		assert_ok!(RMRKCore::accept_resource(Origin::signed(BOB), 0, 0, 0));
-		// Valid resource acceptance should trigger a ResourceAccepted event
-		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceAccepted {
-			nft_id: 0,
-			resource_id: 0, // resource_id
-		}));
-		// Resource should now have false pending status
-		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().pending, false);
-		// Accepting resource again should fail with ResourceNotPending
-		assert_noop!(
-			RMRKCore::accept_resource(Origin::signed(BOB), 0, 0, 0),
-			Error::<Test>::ResourceNotPending
-		);
+		// Deposit is released back to ALICE
+		assert_eq!(Balances::reserved_balance(ALICE), reserved);
+		assert_eq!(RMRKCore::collection_deposits(COLLECTION_ID_0), 0);
 	});
 }
 
-/// Resource: Basic resource removal
+/// Deposit: Properties reserve a per-byte deposit, adjusted on update and released on burn
 #[test]
-fn resource_removal_works() {
+fn property_deposit_works() {
 	ExtBuilder::default().build().execute_with(|| {
 		// Create a basic collection
 		assert_ok!(basic_collection());
 		// Mint NFT
 		assert_ok!(basic_mint());
-
-		let basic_resource =
-			BasicResource { src: None, metadata: None, license: None, thumb: None };
-
-		// Add resource to NFT
-		assert_ok!(RMRKCore::add_basic_resource(
+		let reserved = Balances::reserved_balance(ALICE);
+		// ALICE sets property on NFT
+		assert_ok!(RMRKCore::set_property(
 			Origin::signed(ALICE),
 			COLLECTION_ID_0,
-			NFT_ID_0,
-			basic_resource,
+			Some(NFT_ID_0),
+			stbk("key"),
+			stb("long-value")
 		));
-		// Resource res-1 doesn't exist
-		assert_noop!(
-			RMRKCore::remove_resource(
-				Origin::signed(ALICE),
-				COLLECTION_ID_0,
-				NFT_ID_0,
-				1, // resource_id
-			),
-			Error::<Test>::ResourceDoesntExist
-		);
-		// Only collection issuer can request resource removal
-		assert_noop!(
-			RMRKCore::remove_resource(
-				Origin::signed(BOB),
-				COLLECTION_ID_0,
-				NFT_ID_0,
-				0, // resource_id
-			),
-			Error::<Test>::NoPermission
+		// Deposit covers 3 key bytes and 10 value bytes
+		assert_eq!(
+			Balances::reserved_balance(ALICE),
+			reserved + 13 * PropertyDepositPerByte::get()
 		);
-		// Remove resource
-		assert_ok!(RMRKCore::remove_resource(
+		// Shrinking the value releases the difference
+		assert_ok!(RMRKCore::set_property(
 			Origin::signed(ALICE),
 			COLLECTION_ID_0,
-			NFT_ID_0,
-			0, // resource_id
+			Some(NFT_ID_0),
+			stbk("key"),
+			stb("short")
 		));
-		// Successful resource removal should trigger ResourceRemoval event
-		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceRemoval {
-			nft_id: 0,
-			resource_id: 0, // resource_id
-		}));
-		// Since ALICE rootowns NFT, resource should be removed
-		assert_eq!(RMRKCore::resources((0, 0, 0)), None);
+		assert_eq!(Balances::reserved_balance(ALICE), reserved + 8 * PropertyDepositPerByte::get());
+		// Burning the NFT removes its properties and releases the deposit
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(ALICE), COLLECTION_ID_0, NFT_ID_0, 1));
+		assert_eq!(RMRKCore::properties((COLLECTION_ID_0, Some(NFT_ID_0), stbk("key"))), None);
+		assert_eq!(RMRKCore::collection_deposits(COLLECTION_ID_0), 0);
 	});
 }
 
-/// Resource: Resource removal with pending and accept
+/// Deposit: Properties written by NFT owners are paid for by the owners
 #[test]
-fn resource_removal_pending_works() {
+fn owner_written_property_deposit_works() {
 	ExtBuilder::default().build().execute_with(|| {
 		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint NFT
+		// ALICE mints NFT (0, 0) for BOB and lets both write the "mood" key
 		assert_ok!(RMRKCore::mint_nft(
 			Origin::signed(ALICE),
 			Some(BOB),
 			COLLECTION_ID_0,
-			Some(BOB),
-			Some(Permill::from_float(1.525)),
+			None,
 			bvec![0u8; 20],
 			true,
 			None
 		));
-
-		let basic_resource =
-			BasicResource { src: None, metadata: None, license: None, thumb: None };
-
-		// Add resource to NFT
-		assert_ok!(RMRKCore::add_basic_resource(
+		assert_ok!(RMRKCore::set_property_permission(
 			Origin::signed(ALICE),
-			COLLECTION_ID_0,
-			NFT_ID_0,
-			basic_resource,
+			0,
+			stbk("mood"),
+			PropertyPermission::IssuerAndOwner
 		));
-
-		assert_ok!(RMRKCore::accept_resource(Origin::signed(BOB), COLLECTION_ID_0, NFT_ID_0, 0,));
-		// Accepting a resource removal that is not pending should fail
-		assert_noop!(
-			RMRKCore::accept_resource_removal(Origin::signed(BOB), 0, 0, 0),
-			Error::<Test>::ResourceNotPending
+		let alice_reserved = Balances::reserved_balance(ALICE);
+		let bob_reserved = Balances::reserved_balance(BOB);
+		// BOB sets the property and pays its deposit
+		assert_ok!(RMRKCore::set_property(
+			Origin::signed(BOB),
+			0,
+			Some(0),
+			stbk("mood"),
+			stb("happy")
+		));
+		assert_eq!(
+			Balances::reserved_balance(BOB),
+			bob_reserved + 9 * PropertyDepositPerByte::get()
 		);
-		// Only collection's issuer can request resource removal
-		assert_noop!(
-			RMRKCore::remove_resource(
-				Origin::signed(BOB),
-				COLLECTION_ID_0,
-				NFT_ID_0,
-				0, // resource_id
-			),
-			Error::<Test>::NoPermission
+		assert_eq!(Balances::reserved_balance(ALICE), alice_reserved);
+		assert_eq!(
+			RMRKCore::property_deposits((0, Some(0), stbk("mood"))),
+			Some((BOB, 9 * PropertyDepositPerByte::get()))
 		);
-		// Resource removal requested by the collection issuer
-		assert_ok!(RMRKCore::remove_resource(
+		// ALICE overwrites it, BOB's deposit is released and ALICE pays for the new value
+		assert_ok!(RMRKCore::set_property(
 			Origin::signed(ALICE),
-			COLLECTION_ID_0,
-			NFT_ID_0,
-			0, // resource_id
+			0,
+			Some(0),
+			stbk("mood"),
+			stb("sad")
 		));
-		// Since ALICE doesn't root-own NFT, resource's removal is waiting for acceptance
-		assert_eq!(RMRKCore::resources((0, 0, 0)).unwrap().pending_removal, true);
-		// ALICE doesn't own BOB's NFT, so accept should fail
-		assert_noop!(
-			RMRKCore::accept_resource_removal(Origin::signed(ALICE), 0, 0, 0),
-			Error::<Test>::NoPermission
+		assert_eq!(Balances::reserved_balance(BOB), bob_reserved);
+		assert_eq!(
+			Balances::reserved_balance(ALICE),
+			alice_reserved + 7 * PropertyDepositPerByte::get()
 		);
-		// Resource res-1 doesn't exist
-		assert_noop!(
-			RMRKCore::accept_resource_removal(Origin::signed(BOB), 0, 0, 1),
-			Error::<Test>::ResourceDoesntExist
+		assert_eq!(RMRKCore::property_deposits((0, Some(0), stbk("mood"))), None);
+		// BOB overwrites it again, ALICE's deposit is released
+		assert_ok!(RMRKCore::set_property(
+			Origin::signed(BOB),
+			0,
+			Some(0),
+			stbk("mood"),
+			stb("glad")
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), alice_reserved);
+		assert_eq!(
+			Balances::reserved_balance(BOB),
+			bob_reserved + 8 * PropertyDepositPerByte::get()
 		);
-		// BOB can accept his own NFT's pending resource removal
-		assert_ok!(RMRKCore::accept_resource_removal(Origin::signed(BOB), 0, 0, 0));
-		// Successful resource removal acceptance should trigger ResourceRemovalAccepted event
-		System::assert_last_event(MockEvent::RmrkCore(crate::Event::ResourceRemovalAccepted {
-			nft_id: 0,
-			resource_id: 0, // resource_id
-		}));
-		// Resource removed
-		assert_eq!(RMRKCore::resources((0, 0, 0)), None);
+		// Removing the property releases BOB's deposit
+		assert_ok!(RMRKCore::remove_property(Origin::signed(BOB), 0, Some(0), stbk("mood")));
+		assert_eq!(Balances::reserved_balance(BOB), bob_reserved);
+		assert_eq!(RMRKCore::property_deposits((0, Some(0), stbk("mood"))), None);
+		// So does burning the NFT
+		assert_ok!(RMRKCore::set_property(
+			Origin::signed(BOB),
+			0,
+			Some(0),
+			stbk("mood"),
+			stb("happy")
+		));
+		assert_ok!(RMRKCore::burn_nft(Origin::signed(BOB), 0, 0, 1));
+		assert_eq!(Balances::reserved_balance(BOB), bob_reserved);
+		assert_eq!(Balances::reserved_balance(ALICE), alice_reserved);
+		assert_no_orphaned_keys();
 	});
 }
 
-/// Property: Setting property tests (RMRK2.0 spec: SETPROPERTY)
+/// Deposit: NFTs owned by another NFT reserve a child deposit, released when sent away
 #[test]
-fn set_property_works() {
+fn child_deposit_works() {
 	ExtBuilder::default().build().execute_with(|| {
-		// Define property key
-		let key = stbk("test-key");
-		// Define property value
-		let value = stb("test-value");
-		// set_property fails without a collection (CollectionUnknown)
-		assert_noop!(
-			RMRKCore::set_property(Origin::signed(ALICE), 0, Some(0), key.clone(), value.clone()),
-			Error::<Test>::CollectionUnknown
-		);
 		// Create a basic collection
 		assert_ok!(basic_collection());
-		// Mint NFT
+		// Mint two NFTs
 		assert_ok!(basic_mint());
-		// ALICE sets property on NFT
-		assert_ok!(RMRKCore::set_property(
+		assert_ok!(basic_mint());
+		let reserved = Balances::reserved_balance(ALICE);
+		// ALICE sends NFT (0, 1) to NFT (0, 0)
+		assert_ok!(RMRKCore::send(
 			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(COLLECTION_ID_0, NFT_ID_0),
+		));
+		// ALICE reserved the child deposit
+		assert_eq!(Balances::reserved_balance(ALICE), reserved + ChildDeposit::get());
+		assert_eq!(
+			RMRKCore::child_deposits((COLLECTION_ID_0, 1)),
+			Some((ALICE, ChildDeposit::get()))
+		);
+		// ALICE sends NFT (0, 1) back to her account
+		assert_ok!(RMRKCore::send(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			1,
+			AccountIdOrCollectionNftTuple::AccountId(ALICE),
+		));
+		// Child deposit is released
+		assert_eq!(Balances::reserved_balance(ALICE), reserved);
+		assert_eq!(RMRKCore::child_deposits((COLLECTION_ID_0, 1)), None);
+	});
+}
+
+/// Deposit: A rejected NFT whose sender cannot pay the child deposit is returned to the
+/// sender's account
+#[test]
+fn reject_without_child_deposit_returns_to_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		use frame_support::traits::{Currency, ReservableCurrency};
+		// Create a basic collection
+		assert_ok!(basic_collection());
+		// ALICE mints (0, 0) and (0, 1) for BOB and (0, 2) for ALICE
+		for _ in 0..2 {
+			assert_ok!(RMRKCore::mint_nft(
+				Origin::signed(ALICE),
+				Some(BOB),
+				COLLECTION_ID_0,
+				None,
+				bvec![0u8; 20],
+				true,
+				None
+			));
+		}
+		assert_ok!(basic_mint());
+		// BOB nests NFT (0, 1) under NFT (0, 0), then sends it to ALICE's NFT (0, 2)
+		assert_ok!(RMRKCore::send(
+			Origin::signed(BOB),
 			0,
-			Some(0),
-			key.clone(),
-			value.clone()
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
 		));
-		// Successful property setting should trigger a PropertySet event
-		System::assert_last_event(MockEvent::RmrkCore(crate::Event::PropertySet {
-			collection_id: 0,
-			maybe_nft_id: Some(0),
-			key: key.clone(),
-			value: value.clone(),
-		}));
-		// Property value now exists
-		assert_eq!(RMRKCore::properties((0, Some(0), key.clone())).unwrap(), value.clone());
-		// BOB does not own NFT so attempt to set property should fail
-		assert_noop!(
-			RMRKCore::set_property(Origin::signed(BOB), 0, Some(0), key.clone(), value.clone()),
-			Error::<Test>::NoPermission
+		assert_ok!(RMRKCore::send(
+			Origin::signed(BOB),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 2),
+		));
+		// NFT (0, 1) holds no child deposit, as if nested before deposits existed, and BOB has
+		// no funds left
+		ChildDeposits::<Test>::remove((0, 1));
+		Balances::unreserve(&BOB, ChildDeposit::get());
+		Balances::make_free_balance_be(&BOB, 0);
+		// ALICE rejects NFT (0, 1), which BOB cannot pay to nest under NFT (0, 0) again
+		assert_ok!(RMRKCore::reject_nft(Origin::signed(ALICE), 0, 1, false));
+		assert_eq!(UNQ::Pallet::<Test>::owner(0, 1), Some(BOB));
+		assert_eq!(
+			RMRKCore::nfts(0, 1).unwrap().owner,
+			AccountIdOrCollectionNftTuple::AccountId(BOB)
 		);
+		assert!(RMRKCore::children((0, 0), (0, 1)).is_none());
+		assert_eq!(RMRKCore::child_deposits((0, 1)), None);
 	});
 }
 
@@ -3,6 +3,7 @@
 // License: Apache 2.0 modified by RMRK, see LICENSE.md
 
 use frame_support::pallet_prelude::*;
+use rmrk_traits::{primitives::NftId, AccountIdOrCollectionNftTuple};
 use sp_runtime::Permill;
 
 use scale_info::TypeInfo;
@@ -28,3 +29,55 @@ pub struct InstanceInfo<AccountId, BoundedString> {
 	/// Arbitrary data about an instance, e.g. IPFS hash
 	pub metadata: BoundedString,
 }
+
+/// Origin of a pending NFT, recorded when it is sent (or minted) to a non-owned recipient so
+/// that a rejection or timeout can return it instead of burning it.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingNftInfo<AccountId, BlockNumber> {
+	/// Account that sent the NFT, i.e. its root owner at the time of sending
+	pub sender: AccountId,
+	/// Owner of the NFT before it was sent, either an account or an NFT
+	pub previous_owner: AccountIdOrCollectionNftTuple<AccountId>,
+	/// Block at which the NFT became pending
+	pub since: BlockNumber,
+}
+
+/// A single NFT to be minted by `mint_nft_batch`, mirroring the arguments of `mint_nft`.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub struct MintBatchEntry<AccountId, RoyaltyInfo, BoundedString, BoundedResources> {
+	/// Owner of the NFT, defaults to the minter
+	pub owner: Option<AccountId>,
+	/// Recipients of the royalty and their permillage reward from each trade
+	pub royalty: Option<RoyaltyInfo>,
+	/// Arbitrary data about an NFT, e.g. IPFS hash
+	pub metadata: BoundedString,
+	pub transferable: bool,
+	/// Resources added to the NFT at mint
+	pub resources: Option<BoundedResources>,
+}
+
+/// NFTs of a collection targeted by `add_resource_to_nfts`.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub enum ResourceTargets<BoundedNfts> {
+	/// An explicit list of NFTs, all of which must exist
+	Nfts(BoundedNfts),
+	/// Every existing NFT with an id from `first` to `last` inclusive, skipping burned ids
+	Range { first: NftId, last: NftId },
+}
+
+/// Policy an account applies to NFTs that other accounts send or mint under its NFTs.
+///
+/// Blocked collections are refused first, then NFTs from auto-accepted collections or senders
+/// skip the pending state. Any other NFT is refused if `refuse_all` is set and otherwise becomes
+/// pending as usual.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct IncomingPolicy<BoundedCollections, BoundedAccounts> {
+	/// Refuse every incoming NFT that isn't auto-accepted
+	pub refuse_all: bool,
+	/// Collections whose NFTs are always refused
+	pub blocked_collections: BoundedCollections,
+	/// Collections whose NFTs are accepted without going through the pending state
+	pub auto_accept_collections: BoundedCollections,
+	/// Senders whose NFTs are accepted without going through the pending state
+	pub auto_accept_senders: BoundedAccounts,
+}
@@ -147,9 +147,12 @@ where
 			pallet_uniques::Error::<T>::Locked
 		);
 
-		let item_is_equipped =
+		let equipped_item =
 			Equippings::<T>::get(((equipper_collection_id, equipper_nft_id), base_id, slot_id))
-				.is_some();
+				.map(|(equipped_item, _)| equipped_item);
+		let item_is_equipped = equipped_item == Some(item);
+		// A slot holds a single item, so another item cannot be equipped (or unequipped) there
+		ensure!(equipped_item.is_none() || item_is_equipped, Error::<T>::SlotAlreadyEquipped);
 		let item_exists =
 			pallet_rmrk_core::Pallet::<T>::nfts(item_collection_id, item_nft_id).is_some();
 
@@ -288,7 +291,7 @@ where
 				// Equip item (add to Equippings)
 				Equippings::<T>::insert(
 					((equipper_collection_id, equipper_nft_id), base_id, slot_id),
-					resource_id,
+					(item, resource_id),
 				);
 
 				// Update item's equipped property
@@ -392,3 +395,98 @@ where
 		Ok(())
 	}
 }
+
+impl<T: Config> OnResourceRemoved for Pallet<T> {
+	/// Unequips whatever relied on a removed resource:
+	/// - items equipped into a base the equipper no longer has a composable resource for
+	/// - the NFT itself, if it was equipped through the removed slot resource
+	fn on_resource_removed(
+		collection_id: CollectionId,
+		nft_id: NftId,
+		resource_id: ResourceId,
+		slots: &[(BaseId, SlotId)],
+		released_base: Option<BaseId>,
+	) {
+		if let Some(base_id) = released_base {
+			for (slot_id, (item, _)) in
+				Equippings::<T>::drain_prefix(((collection_id, nft_id), base_id))
+			{
+				Self::mark_unequipped(item, base_id, slot_id);
+			}
+		}
+
+		if let Some(AccountIdOrCollectionNftTuple::CollectionAndNftTuple(
+			equipper_collection_id,
+			equipper_nft_id,
+		)) = pallet_rmrk_core::Pallet::<T>::nfts(collection_id, nft_id).map(|nft| nft.owner)
+		{
+			for (base_id, slot_id) in slots {
+				let equipping = ((equipper_collection_id, equipper_nft_id), *base_id, *slot_id);
+				if Equippings::<T>::get(equipping) == Some(((collection_id, nft_id), resource_id)) {
+					Equippings::<T>::remove(equipping);
+					Self::mark_unequipped((collection_id, nft_id), *base_id, *slot_id);
+				}
+			}
+		}
+	}
+}
+
+impl<T: Config> OnNftBurned for Pallet<T> {
+	/// Unequips the items equipped by a burned NFT, and the NFT itself if it is equipped
+	fn on_nft_burned(collection_id: CollectionId, nft_id: NftId) {
+		for ((base_id, slot_id), (item, _)) in
+			Equippings::<T>::drain_prefix(((collection_id, nft_id),))
+		{
+			Self::mark_unequipped(item, base_id, slot_id);
+		}
+
+		let nft = match pallet_rmrk_core::Pallet::<T>::nfts(collection_id, nft_id) {
+			Some(nft) if nft.equipped => nft,
+			_ => return,
+		};
+		if let AccountIdOrCollectionNftTuple::CollectionAndNftTuple(
+			equipper_collection_id,
+			equipper_nft_id,
+		) = nft.owner
+		{
+			let equipper = (equipper_collection_id, equipper_nft_id);
+			let equipping = Equippings::<T>::iter_prefix((equipper,))
+				.find(|(_, (item, _))| *item == (collection_id, nft_id));
+			if let Some(((base_id, slot_id), _)) = equipping {
+				Equippings::<T>::remove((equipper, base_id, slot_id));
+				Self::mark_unequipped((collection_id, nft_id), base_id, slot_id);
+			}
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Clears the equipped flag of an item whose equipping was removed
+	fn mark_unequipped(item: (CollectionId, NftId), base_id: BaseId, slot_id: SlotId) {
+		pallet_rmrk_core::Nfts::<T>::mutate_exists(item.0, item.1, |nft| {
+			if let Some(nft) = nft {
+				nft.equipped = false;
+			}
+		});
+		Self::deposit_event(Event::SlotUnequipped {
+			item_collection: item.0,
+			item_nft: item.1,
+			base_id,
+			slot_id,
+		});
+	}
+}
+
+impl<T: Config> InspectBase for Pallet<T> {
+	fn base_exists(base_id: BaseId) -> bool {
+		Bases::<T>::contains_key(base_id)
+	}
+
+	fn part_exists(base_id: BaseId, part_id: PartId) -> bool {
+		Parts::<T>::contains_key(base_id, part_id)
+	}
+
+	fn is_slot_part(base_id: BaseId, part_id: PartId) -> bool {
+		matches!(Parts::<T>::get(base_id, part_id), Some(PartType::SlotPart(_)))
+	}
+}
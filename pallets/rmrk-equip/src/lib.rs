@@ -16,11 +16,12 @@ pub use pallet::*;
 
 use rmrk_traits::{
 	primitives::*, AccountIdOrCollectionNftTuple, Base, BaseInfo, BasicResource,
-	ComposableResource, EquippableList, PartType, ResourceTypes, SlotResource, Theme,
-	ThemeProperty,
+	ComposableResource, EquippableList, InspectBase, OnNftBurned, OnResourceRemoved, PartType,
+	ResourceTypes, SlotResource, Theme, ThemeProperty,
 };
 
 mod functions;
+pub mod migration;
 
 #[cfg(test)]
 mod mock;
@@ -113,7 +114,7 @@ pub mod pallet {
 
 	#[pallet::storage]
 	#[pallet::getter(fn equippings)]
-	/// Stores Equippings info ((equipper, base, slot), equipped_resource)
+	/// Stores Equippings info ((equipper, base, slot), (equipped_item, equipped_resource))
 	pub type Equippings<T: Config> = StorageNMap<
 		_,
 		(
@@ -121,7 +122,7 @@ pub mod pallet {
 			NMapKey<Blake2_128Concat, BaseId>,                // Base ID
 			NMapKey<Blake2_128Concat, SlotId>,                // Slot ID
 		),
-		ResourceId, // Equipped Resource
+		((CollectionId, NftId), ResourceId), // Equipped Item and Resource
 		OptionQuery,
 	>;
 
@@ -139,10 +140,31 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migration::v1::migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			migration::try_runtime::pre_upgrade::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			migration::try_runtime::post_upgrade::<T>()
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -212,6 +234,8 @@ pub mod pallet {
 		NeedsDefaultThemeFirst,
 		// Equipped item cannot be equipped elsewhere (without first unequipping)
 		AlreadyEquipped,
+		// Slot is occupied by another item, which must be unequipped first
+		SlotAlreadyEquipped,
 		// Error that should not occur
 		// TODO is this being used?
 		UnknownError,
@@ -0,0 +1,131 @@
+// Copyright (C) 2021-2022 RMRK
+// This file is part of rmrk-equip.
+// License: Apache 2.0 modified by RMRK, see LICENSE.md
+
+//! Storage migrations, run in order from `on_runtime_upgrade`.
+//!
+//! Migrations walk all of `Equippings` within the block of the upgrade, which assumes no more
+//! than a few thousand equipped items. Run the upgrade with `try-runtime` against a copy of the
+//! chain state first.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, StorageVersion},
+};
+
+/// Records the equipped item alongside the equipped resource in `Equippings`.
+pub mod v1 {
+	use super::*;
+
+	/// Whether an NFT is equipped.
+	///
+	/// This pallet is upgraded ahead of rmrk-core, whose NFTs may still be stored in their
+	/// pre-v1 layout, so those are decoded in that layout.
+	fn is_equipped<T: Config>(collection_id: CollectionId, nft_id: NftId) -> bool {
+		use pallet_rmrk_core::migration::v1::OldNftInfoOf;
+
+		if pallet_rmrk_core::Pallet::<T>::on_chain_storage_version() < 1 {
+			let key = pallet_rmrk_core::Nfts::<T>::hashed_key_for(collection_id, nft_id);
+			frame_support::storage::unhashed::get::<OldNftInfoOf<T>>(&key)
+				.map_or(false, |nft| nft.equipped)
+		} else {
+			pallet_rmrk_core::Pallet::<T>::nfts(collection_id, nft_id)
+				.map_or(false, |nft| nft.equipped)
+		}
+	}
+
+	/// Finds the child of `equipper` equipped into a base and slot through `item_resource_id`.
+	///
+	/// Before v1 only the resource was stored, so this is the best guess at the equipped item.
+	fn equipped_child<T: Config>(
+		equipper: (CollectionId, NftId),
+		item_resource_id: ResourceId,
+		base_id: BaseId,
+		slot_id: SlotId,
+		reads: &mut u64,
+	) -> Option<(CollectionId, NftId)> {
+		pallet_rmrk_core::Children::<T>::iter_key_prefix(equipper).find(
+			|(collection_id, nft_id)| {
+				*reads += 3;
+				is_equipped::<T>(*collection_id, *nft_id) &&
+					pallet_rmrk_core::Pallet::<T>::equippable_slots((
+						collection_id,
+						nft_id,
+						item_resource_id,
+						base_id,
+						slot_id,
+					))
+					.is_some()
+			},
+		)
+	}
+
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 1 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut reads = 0u64;
+		let mut translated = 0u64;
+		Equippings::<T>::translate::<ResourceId, _>(|(equipper, base_id, slot_id), resource_id| {
+			translated += 1;
+			// Equippings whose item cannot be found are dropped
+			equipped_child::<T>(equipper, resource_id, base_id, slot_id, &mut reads)
+				.map(|item| (item, resource_id))
+		});
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(reads + translated + 1, translated + 1)
+	}
+}
+
+/// Checks run by `try-runtime` around the migrations.
+#[cfg(feature = "try-runtime")]
+pub mod try_runtime {
+	use super::*;
+	use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+	/// Records the number of equippings, which the migrations may only lower.
+	pub fn pre_upgrade<T: Config>() -> Result<(), &'static str> {
+		ensure!(
+			Pallet::<T>::on_chain_storage_version() <= Pallet::<T>::current_storage_version(),
+			"rmrk-equip storage is newer than the runtime"
+		);
+		Pallet::<T>::set_temp_storage(Equippings::<T>::iter_keys().count() as u32, "equippings");
+		Ok(())
+	}
+
+	/// Checks that every equipping decodes and records an equipped child of its equipper.
+	pub fn post_upgrade<T: Config>() -> Result<(), &'static str> {
+		ensure!(
+			Pallet::<T>::on_chain_storage_version() == Pallet::<T>::current_storage_version(),
+			"rmrk-equip storage version was not updated"
+		);
+		let mut equippings = 0u32;
+		for ((equipper, _, _), (item, _)) in Equippings::<T>::iter() {
+			equippings += 1;
+			// v1
+			ensure!(
+				pallet_rmrk_core::Children::<T>::contains_key(equipper, item),
+				"equipped item is not a child of its equipper"
+			);
+			ensure!(
+				pallet_rmrk_core::Pallet::<T>::nfts(item.0, item.1)
+					.map_or(false, |nft| nft.equipped),
+				"equipped item is not marked as equipped"
+			);
+		}
+		ensure!(
+			Equippings::<T>::iter_keys().count() as u32 == equippings,
+			"equippings fail to decode"
+		);
+		ensure!(
+			Pallet::<T>::get_temp_storage::<u32>("equippings")
+				.map_or(false, |before| equippings <= before),
+			"equippings were added"
+		);
+		Ok(())
+	}
+}
@@ -61,11 +61,25 @@ parameter_types! {
 	pub const MaxPriorities: u32 = 3;
 	pub const CollectionSymbolLimit: u32 = 100;
 	pub const MaxResourcesOnMint: u32 = 3;
+	pub const MaxBatchMint: u32 = 5;
+	pub const MaxBatchAccept: u32 = 5;
+	pub const MaxChildrenPerNft: u32 = 5;
+	pub const MaxDescendants: u32 = 8;
+	pub const MaxResourcesPerNft: u32 = 10;
+	pub const MaxPropertiesPerNft: u32 = 10;
+	pub const MaxPolicyEntries: u32 = 5;
+	pub const PendingTimeout: u64 = 10;
+	pub const MaxBulkResources: u32 = 5;
+	pub const MaxRoyaltyRecipients: u32 = 3;
+	pub const MaxPropertiesBatch: u32 = 5;
+	pub const ResourceDeposit: Balance = 10 * RMRK;
+	pub const PropertyDepositPerByte: Balance = RMRK;
+	pub const ChildDeposit: Balance = 10 * RMRK;
 
 }
 
 impl pallet_rmrk_core::Config for Test {
-	// type Currency = Balances;
+	type Currency = Balances;
 	type Event = Event;
 	type ProtocolOrigin = EnsureRoot<AccountId>;
 	type MaxRecursions = MaxRecursions;
@@ -74,6 +88,23 @@ impl pallet_rmrk_core::Config for Test {
 	type MaxPriorities = MaxPriorities;
 	type CollectionSymbolLimit = CollectionSymbolLimit;
 	type MaxResourcesOnMint = MaxResourcesOnMint;
+	type MaxBatchMint = MaxBatchMint;
+	type MaxBatchAccept = MaxBatchAccept;
+	type MaxChildrenPerNft = MaxChildrenPerNft;
+	type MaxDescendants = MaxDescendants;
+	type MaxResourcesPerNft = MaxResourcesPerNft;
+	type MaxPropertiesPerNft = MaxPropertiesPerNft;
+	type MaxPolicyEntries = MaxPolicyEntries;
+	type PendingTimeout = PendingTimeout;
+	type MaxBulkResources = MaxBulkResources;
+	type MaxRoyaltyRecipients = MaxRoyaltyRecipients;
+	type MaxPropertiesBatch = MaxPropertiesBatch;
+	type OnResourceRemoved = RmrkEquip;
+	type OnNftBurned = RmrkEquip;
+	type Bases = RmrkEquip;
+	type ResourceDeposit = ResourceDeposit;
+	type PropertyDepositPerByte = PropertyDepositPerByte;
+	type ChildDeposit = ChildDeposit;
 }
 
 parameter_types! {
@@ -4,7 +4,8 @@
 
 use super::*;
 
-use rmrk_traits::{FixedPart, SlotPart, ThemeProperty};
+use pallet_rmrk_core::RoyaltyInfoOf;
+use rmrk_traits::{FixedPart, RoyaltyInfo, SlotPart, ThemeProperty};
 
 use frame_support::{assert_noop, assert_ok};
 use mock::{Event as MockEvent, *};
@@ -40,6 +41,132 @@ macro_rules! sbvec {
 	}
 }
 
+/// Shortcut for a royalty paid entirely to a single recipient
+fn royalty(
+	recipient: <Test as frame_system::Config>::AccountId,
+	amount: Permill,
+) -> Option<RoyaltyInfoOf<Test>> {
+	Some(RoyaltyInfo { shares: bvec![(recipient, amount)] })
+}
+
+/// Sets up character-0 equipping sword-0 (1, 0) in the left hand (slot 201) and sword-1 (1, 1) in
+/// the right hand (slot 202) of base 0, each sword having resource 0 for the left hand and
+/// resource 1 for the right hand
+fn equip_two_swords() {
+	// Slot parts left hand and right hand can equip items from collection 1
+	let slot_part_left_hand = SlotPart {
+		id: 201,
+		z: 0,
+		src: Some(stb("left-hand")),
+		equippable: EquippableList::Custom(bvec![1]),
+	};
+	let slot_part_right_hand = SlotPart {
+		id: 202,
+		z: 0,
+		src: Some(stb("right-hand")),
+		equippable: EquippableList::Custom(bvec![1]),
+	};
+	// Create a base with the slot parts
+	assert_ok!(RmrkEquip::create_base(
+		Origin::signed(ALICE),
+		stb("svg"),
+		stb("KANPEOPLE"),
+		bvec![PartType::SlotPart(slot_part_left_hand), PartType::SlotPart(slot_part_right_hand)],
+	));
+	// Create collection 0 (characters) and collection 1 (swords)
+	assert_ok!(RmrkCore::create_collection(
+		Origin::signed(ALICE),
+		stb("ipfs://col0-metadata"),
+		Some(5),
+		sbvec!["COL0"],
+		None,
+	));
+	assert_ok!(RmrkCore::create_collection(
+		Origin::signed(ALICE),
+		stb("ipfs://col1-metadata"),
+		Some(5),
+		sbvec!["COL1"],
+		None,
+	));
+	// Mint character-0 and two swords, and send both swords to character-0
+	assert_ok!(RmrkCore::mint_nft(
+		Origin::signed(ALICE),
+		Some(ALICE),
+		0,
+		None,
+		stb("ipfs://character-0-metadata"),
+		true,
+		None,
+	));
+	for sword in 0..2 {
+		assert_ok!(RmrkCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(ALICE),
+			1,
+			None,
+			stb("ipfs://sword-metadata"),
+			true,
+			None,
+		));
+		assert_ok!(RmrkCore::send(
+			Origin::signed(ALICE),
+			1,
+			sword,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+		));
+	}
+	// Add a composable resource to character-0
+	assert_ok!(RmrkCore::add_composable_resource(
+		Origin::signed(ALICE),
+		0,
+		0,
+		ComposableResource {
+			parts: bvec![201, 202],
+			src: None,
+			base: 0,
+			license: None,
+			metadata: None,
+			slot: None,
+			thumb: None,
+		},
+	));
+	// Each sword gets a left-hand resource (ID 0) and a right-hand resource (ID 1)
+	for sword in 0..2 {
+		for slot in [201, 202] {
+			assert_ok!(RmrkCore::add_slot_resource(
+				Origin::signed(ALICE),
+				1,
+				sword,
+				SlotResource {
+					src: Some(stbd("ipfs://sword-metadata-hand")),
+					base: 0,
+					license: None,
+					metadata: None,
+					slot,
+					thumb: None,
+				},
+			));
+		}
+	}
+	// Equip sword-0 in the left hand and sword-1 in the right hand
+	assert_ok!(RmrkEquip::equip(Origin::signed(ALICE), (1, 0), (0, 0), 0, 0, 201));
+	assert_ok!(RmrkEquip::equip(Origin::signed(ALICE), (1, 1), (0, 0), 1, 0, 202));
+}
+
+/// Asserts every equipping has an existing equipper, and an existing equipped item it is the
+/// direct parent of
+fn assert_no_orphaned_equippings() {
+	for ((equipper, _, _), (item, _)) in pallet::Equippings::<Test>::iter() {
+		assert!(RmrkCore::nfts(equipper.0, equipper.1).is_some(), "equipper {:?}", equipper);
+		let item_info = RmrkCore::nfts(item.0, item.1).expect("equipped item exists");
+		assert!(item_info.equipped, "item {:?} not flagged equipped", item);
+		assert_eq!(
+			item_info.owner,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(equipper.0, equipper.1)
+		);
+	}
+}
+
 /// Base: Basic base tests
 #[test]
 fn create_base_works() {
@@ -160,7 +287,8 @@ fn equip_works() {
 			Origin::signed(ALICE),
 			stb("ipfs://col0-metadata"), // metadata
 			Some(5),                     // max
-			sbvec!["COL0"]               // symbol
+			sbvec!["COL0"],              // symbol
+			None,                        // royalty
 		));
 
 		// Create collection 1
@@ -168,17 +296,17 @@ fn equip_works() {
 			Origin::signed(ALICE),
 			stb("ipfs://col1-metadata"), // metadata
 			Some(5),                     // max
-			sbvec!["COL1"]               // symbol
+			sbvec!["COL1"],              // symbol
+			None,                        // royalty
 		));
 
 		// Mint NFT 0 from collection 0 (character-0)
 		assert_ok!(RmrkCore::mint_nft(
 			Origin::signed(ALICE),
-			Some(ALICE),                        // owner
-			0,                                  // collection ID
-			Some(ALICE),                        // recipient
-			Some(Permill::from_float(1.525)),   // royalties
-			stb("ipfs://character-0-metadata"), // metadata
+			Some(ALICE),                                // owner
+			0,                                          // collection ID
+			royalty(ALICE, Permill::from_float(1.525)), // royalty
+			stb("ipfs://character-0-metadata"),         // metadata
 			true,
 			None,
 		));
@@ -186,11 +314,10 @@ fn equip_works() {
 		// Mint NFT 1 from collection 0 (character-1)
 		assert_ok!(RmrkCore::mint_nft(
 			Origin::signed(ALICE),
-			Some(ALICE),                        // owner
-			0,                                  // collection ID
-			Some(ALICE),                        // recipient
-			Some(Permill::from_float(1.525)),   // royalties
-			stb("ipfs://character-1-metadata"), // metadata
+			Some(ALICE),                                // owner
+			0,                                          // collection ID
+			royalty(ALICE, Permill::from_float(1.525)), // royalty
+			stb("ipfs://character-1-metadata"),         // metadata
 			true,
 			None,
 		));
@@ -198,11 +325,10 @@ fn equip_works() {
 		// Mint NFT 0 from collection 1 (sword)
 		assert_ok!(RmrkCore::mint_nft(
 			Origin::signed(ALICE),
-			Some(ALICE),                      // owner
-			1,                                // collection ID
-			Some(ALICE),                      // recipient
-			Some(Permill::from_float(1.525)), // royalties
-			stb("ipfs://sword-metadata"),     // metadata
+			Some(ALICE),                                // owner
+			1,                                          // collection ID
+			royalty(ALICE, Permill::from_float(1.525)), // royalty
+			stb("ipfs://sword-metadata"),               // metadata
 			true,
 			None,
 		));
@@ -210,11 +336,10 @@ fn equip_works() {
 		// Mint NFT 1 from collection 1 (flashlight)
 		assert_ok!(RmrkCore::mint_nft(
 			Origin::signed(ALICE),
-			Some(ALICE),                       // owner
-			1,                                 // collection ID
-			Some(ALICE),                       // recipient
-			Some(Permill::from_float(1.525)),  // royalties
-			stb("ipfs://flashlight-metadata"), // metadata
+			Some(ALICE),                                // owner
+			1,                                          // collection ID
+			royalty(ALICE, Permill::from_float(1.525)), // royalty
+			stb("ipfs://flashlight-metadata"),          // metadata
 			true,
 			None,
 		));
@@ -324,10 +449,10 @@ fn equip_works() {
 		// Equipped resource ID 0 should now be associated with equippings for character-0
 		// on base 0, slot 201
 		let equipped = RmrkEquip::equippings(((0, 0), 0, 201));
-		assert_eq!(equipped.clone().unwrap(), 0,);
+		assert_eq!(equipped.clone().unwrap(), ((1, 0), 0),);
 
 		// Resource for equipped item should exist
-		assert!(RmrkCore::resources((1, 0, equipped.unwrap())).is_some());
+		assert!(RmrkCore::resources((1, 0, equipped.unwrap().1)).is_some());
 
 		let sword_slot_resource_right = SlotResource {
 			src: Some(stbd("ipfs://sword-metadata-right")),
@@ -419,6 +544,370 @@ fn equip_works() {
 	});
 }
 
+/// Base: Removing a resource unequips the items relying on it
+#[test]
+fn resource_removal_unequips_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Slot part left hand can equip items from collection 1
+		let slot_part_left_hand = SlotPart {
+			id: 201,
+			z: 0,
+			src: Some(stb("left-hand")),
+			equippable: EquippableList::Custom(bvec![1]),
+		};
+		// Create a base with the slot part
+		assert_ok!(RmrkEquip::create_base(
+			Origin::signed(ALICE),
+			stb("svg"),
+			stb("KANPEOPLE"),
+			bvec![PartType::SlotPart(slot_part_left_hand)],
+		));
+		// Create collection 0 (characters) and collection 1 (swords)
+		assert_ok!(RmrkCore::create_collection(
+			Origin::signed(ALICE),
+			stb("ipfs://col0-metadata"),
+			Some(5),
+			sbvec!["COL0"],
+			None,
+		));
+		assert_ok!(RmrkCore::create_collection(
+			Origin::signed(ALICE),
+			stb("ipfs://col1-metadata"),
+			Some(5),
+			sbvec!["COL1"],
+			None,
+		));
+		// Mint character-0 and a sword, and send the sword to character-0
+		assert_ok!(RmrkCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(ALICE),
+			0,
+			None,
+			stb("ipfs://character-0-metadata"),
+			true,
+			None,
+		));
+		assert_ok!(RmrkCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(ALICE),
+			1,
+			None,
+			stb("ipfs://sword-metadata"),
+			true,
+			None,
+		));
+		assert_ok!(RmrkCore::send(
+			Origin::signed(ALICE),
+			1,
+			0,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+		));
+		let slot_resource = SlotResource {
+			src: Some(stbd("ipfs://sword-metadata-left")),
+			base: 0,
+			license: None,
+			metadata: None,
+			slot: 201,
+			thumb: None,
+		};
+		// Add a composable resource to character-0 and a slot resource to the sword
+		assert_ok!(RmrkCore::add_composable_resource(
+			Origin::signed(ALICE),
+			0,
+			0,
+			ComposableResource {
+				parts: bvec![201],
+				src: None,
+				base: 0,
+				license: None,
+				metadata: None,
+				slot: None,
+				thumb: None,
+			},
+		));
+		assert_ok!(RmrkCore::add_slot_resource(Origin::signed(ALICE), 1, 0, slot_resource.clone()));
+		// Equip the sword
+		assert_ok!(RmrkEquip::equip(Origin::signed(ALICE), (1, 0), (0, 0), 0, 0, 201));
+		// Removing the sword's slot resource unequips the sword
+		assert_ok!(RmrkCore::remove_resource(Origin::signed(ALICE), 1, 0, 0));
+		assert_eq!(RmrkEquip::equippings(((0, 0), 0, 201)), None);
+		assert!(!RmrkCore::nfts(1, 0).unwrap().equipped);
+		System::assert_has_event(MockEvent::RmrkEquip(crate::Event::SlotUnequipped {
+			item_collection: 1,
+			item_nft: 0,
+			base_id: 0,
+			slot_id: 201,
+		}));
+		// Add a new slot resource to the sword and equip it again
+		assert_ok!(RmrkCore::add_slot_resource(Origin::signed(ALICE), 1, 0, slot_resource));
+		assert_ok!(RmrkEquip::equip(Origin::signed(ALICE), (1, 0), (0, 0), 1, 0, 201));
+		// Removing character-0's only resource for the base unequips the sword
+		assert_ok!(RmrkCore::remove_resource(Origin::signed(ALICE), 0, 0, 0));
+		assert_eq!(RmrkCore::equippable_bases((0, 0, 0)), None);
+		assert_eq!(RmrkEquip::equippings(((0, 0), 0, 201)), None);
+		assert!(!RmrkCore::nfts(1, 0).unwrap().equipped);
+	});
+}
+
+/// Base: Only the item equipped in a slot can be unequipped from it
+#[test]
+fn unequip_only_equipped_item_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		equip_two_swords();
+		// Sword-1 cannot be equipped in, nor unequip sword-0 from, the left hand
+		assert_noop!(
+			RmrkEquip::equip(Origin::signed(ALICE), (1, 1), (0, 0), 0, 0, 201),
+			Error::<Test>::SlotAlreadyEquipped
+		);
+		// Removing sword-1's unused left-hand resource leaves sword-0 equipped
+		assert_ok!(RmrkCore::remove_resource(Origin::signed(ALICE), 1, 1, 0));
+		assert_eq!(RmrkEquip::equippings(((0, 0), 0, 201)), Some(((1, 0), 0)));
+		assert!(RmrkCore::nfts(1, 0).unwrap().equipped);
+		assert!(RmrkCore::nfts(1, 1).unwrap().equipped);
+		// Removing character-0's resource unequips exactly the two equipped swords
+		assert_ok!(RmrkCore::remove_resource(Origin::signed(ALICE), 0, 0, 0));
+		assert_eq!(RmrkEquip::equippings(((0, 0), 0, 201)), None);
+		assert_eq!(RmrkEquip::equippings(((0, 0), 0, 202)), None);
+		assert!(!RmrkCore::nfts(1, 0).unwrap().equipped);
+		assert!(!RmrkCore::nfts(1, 1).unwrap().equipped);
+		assert_no_orphaned_equippings();
+	});
+}
+
+/// Base: Burning an NFT removes the equippings of and for it
+#[test]
+fn burn_unequips_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		equip_two_swords();
+		// Burning sword-1 removes its right-hand equipping
+		assert_ok!(RmrkCore::burn_nft(Origin::signed(ALICE), 1, 1, 1));
+		assert_eq!(RmrkEquip::equippings(((0, 0), 0, 202)), None);
+		assert_eq!(RmrkEquip::equippings(((0, 0), 0, 201)), Some(((1, 0), 0)));
+		assert_no_orphaned_equippings();
+		// Burning character-0 removes the equippings keyed by it
+		assert_ok!(RmrkCore::burn_nft(Origin::signed(ALICE), 0, 0, 2));
+		assert_eq!(pallet::Equippings::<Test>::iter_prefix(((0, 0),)).count(), 0);
+		assert_no_orphaned_equippings();
+	});
+}
+
+/// Equip: Equippings written before the equipped item was stored are migrated
+#[test]
+fn equippings_migration_works() {
+	use frame_support::traits::{GetStorageVersion, StorageVersion};
+	ExtBuilder::default().build().execute_with(|| {
+		// Mint a character and a sword, and send the sword to the character
+		assert_ok!(RmrkCore::create_collection(
+			Origin::signed(ALICE),
+			stb("ipfs://col0-metadata"),
+			None,
+			sbvec!["COL0"],
+			None,
+		));
+		for nft in 0..2 {
+			assert_ok!(RmrkCore::mint_nft(
+				Origin::signed(ALICE),
+				Some(ALICE),
+				0,
+				None,
+				stb("ipfs://nft-metadata"),
+				true,
+				None,
+			));
+		}
+		assert_ok!(RmrkCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+		));
+		// The sword was equipped in slot 201 through resource 0, slot 202 has a stale equipping
+		pallet_rmrk_core::EquippableSlots::<Test>::insert((0, 1, 0, 0, 201), ());
+		pallet_rmrk_core::Nfts::<Test>::mutate(0, 1, |nft| nft.as_mut().unwrap().equipped = true);
+		StorageVersion::new(0).put::<RmrkEquip>();
+		for slot in [201, 202] {
+			frame_support::storage::unhashed::put(
+				&pallet::Equippings::<Test>::hashed_key_for(((0, 0), 0, slot)),
+				&0u32,
+			);
+		}
+		crate::migration::v1::migrate::<Test>();
+		// The equipping with a matching child records it, the other one is dropped
+		assert_eq!(RmrkEquip::equippings(((0, 0), 0, 201)), Some(((0, 1), 0)));
+		assert_eq!(RmrkEquip::equippings(((0, 0), 0, 202)), None);
+		assert_eq!(RmrkEquip::on_chain_storage_version(), 1);
+	});
+}
+
+/// Equip: Equippings survive the migration while the NFTs still use the pre-v1 core layout
+#[test]
+fn equippings_migration_before_core_migration_works() {
+	use frame_support::traits::{GetStorageVersion, StorageVersion};
+	use pallet_rmrk_core::migration::v1::{OldNftInfo, OldRoyaltyInfo};
+	ExtBuilder::default().build().execute_with(|| {
+		// Mint a character and a sword, and send the sword to the character
+		assert_ok!(RmrkCore::create_collection(
+			Origin::signed(ALICE),
+			stb("ipfs://col0-metadata"),
+			None,
+			sbvec!["COL0"],
+			None,
+		));
+		for _ in 0..2 {
+			assert_ok!(RmrkCore::mint_nft(
+				Origin::signed(ALICE),
+				Some(ALICE),
+				0,
+				None,
+				stb("ipfs://nft-metadata"),
+				true,
+				None,
+			));
+		}
+		assert_ok!(RmrkCore::send(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+		));
+		pallet_rmrk_core::EquippableSlots::<Test>::insert((0, 1, 0, 0, 201), ());
+		// Both pallets are upgraded from before v1, the equipped sword has a royalty in the old
+		// layout, which does not decode as a current NFT
+		StorageVersion::new(0).put::<RmrkCore>();
+		StorageVersion::new(0).put::<RmrkEquip>();
+		frame_support::storage::unhashed::put(
+			&pallet_rmrk_core::Nfts::<Test>::hashed_key_for(0, 1),
+			&OldNftInfo {
+				owner: AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0),
+				royalty: Some(OldRoyaltyInfo {
+					recipient: ALICE,
+					amount: Permill::from_percent(5),
+				}),
+				metadata: stbd("ipfs://nft-metadata"),
+				equipped: true,
+				pending: false,
+				transferable: true,
+			},
+		);
+		assert_eq!(RmrkCore::nfts(0, 1), None);
+		frame_support::storage::unhashed::put(
+			&pallet::Equippings::<Test>::hashed_key_for(((0, 0), 0, 201)),
+			&0u32,
+		);
+		// The runtime upgrades this pallet ahead of rmrk-core
+		crate::migration::v1::migrate::<Test>();
+		pallet_rmrk_core::migration::v1::migrate::<Test>();
+		// The equipping still records the sword, which can be unequipped
+		assert_eq!(RmrkEquip::equippings(((0, 0), 0, 201)), Some(((0, 1), 0)));
+		assert_eq!(RmrkEquip::on_chain_storage_version(), 1);
+		assert_eq!(RmrkCore::nfts(0, 1).unwrap().equipped, true);
+		assert_ok!(RmrkEquip::equip(Origin::signed(ALICE), (0, 1), (0, 0), 0, 0, 201));
+		assert_eq!(RmrkCore::nfts(0, 1).unwrap().equipped, false);
+	});
+}
+
+/// Base: Resources are validated against bases when added
+#[test]
+fn resource_add_validates_base_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Create a base with a fixed part 101 and a slot part 201
+		assert_ok!(RmrkEquip::create_base(
+			Origin::signed(ALICE),
+			stb("svg"),
+			stb("KANPEOPLE"),
+			bvec![
+				PartType::FixedPart(FixedPart { id: 101, z: 0, src: stb("body") }),
+				PartType::SlotPart(SlotPart {
+					id: 201,
+					z: 0,
+					src: Some(stb("left-hand")),
+					equippable: EquippableList::All,
+				}),
+			],
+		));
+		// Create a collection and mint an NFT
+		assert_ok!(RmrkCore::create_collection(
+			Origin::signed(ALICE),
+			stb("ipfs://col0-metadata"),
+			Some(5),
+			sbvec!["COL0"],
+			None,
+		));
+		assert_ok!(RmrkCore::mint_nft(
+			Origin::signed(ALICE),
+			None,
+			0,
+			None,
+			stb("ipfs://character-0-metadata"),
+			true,
+			None,
+		));
+		let composable_resource = |base: BaseId, parts: Vec<PartId>, slot| ComposableResource {
+			parts: parts.try_into().unwrap(),
+			src: None,
+			base,
+			license: None,
+			metadata: None,
+			slot,
+			thumb: None,
+		};
+		let slot_resource = |base: BaseId, slot: SlotId| SlotResource {
+			src: None,
+			base,
+			license: None,
+			metadata: None,
+			slot,
+			thumb: None,
+		};
+		// Composable resources must reference an existing base
+		assert_noop!(
+			RmrkCore::add_composable_resource(
+				Origin::signed(ALICE),
+				0,
+				0,
+				composable_resource(1, vec![101], None)
+			),
+			pallet_rmrk_core::Error::<Test>::BaseDoesntExist
+		);
+		// Every part must belong to the base
+		assert_noop!(
+			RmrkCore::add_composable_resource(
+				Origin::signed(ALICE),
+				0,
+				0,
+				composable_resource(0, vec![101, 999], None)
+			),
+			pallet_rmrk_core::Error::<Test>::PartDoesntExist
+		);
+		// The slot of a composable resource must be a slot part
+		assert_noop!(
+			RmrkCore::add_composable_resource(
+				Origin::signed(ALICE),
+				0,
+				0,
+				composable_resource(0, vec![101, 201], Some((0, 101)))
+			),
+			pallet_rmrk_core::Error::<Test>::NotASlotPart
+		);
+		assert_ok!(RmrkCore::add_composable_resource(
+			Origin::signed(ALICE),
+			0,
+			0,
+			composable_resource(0, vec![101, 201], Some((0, 201)))
+		));
+		// Slot resources must reference a slot part of an existing base
+		assert_noop!(
+			RmrkCore::add_slot_resource(Origin::signed(ALICE), 0, 0, slot_resource(1, 201)),
+			pallet_rmrk_core::Error::<Test>::BaseDoesntExist
+		);
+		assert_noop!(
+			RmrkCore::add_slot_resource(Origin::signed(ALICE), 0, 0, slot_resource(0, 101)),
+			pallet_rmrk_core::Error::<Test>::NotASlotPart
+		);
+		assert_ok!(RmrkCore::add_slot_resource(Origin::signed(ALICE), 0, 0, slot_resource(0, 201)));
+	});
+}
+
 /// Base: Nested equip tests
 #[test]
 fn nested_equip_works() {
@@ -477,7 +966,8 @@ fn nested_equip_works() {
 			Origin::signed(ALICE),
 			stb("person-collection"), // metadata
 			Some(5),                  // max
-			sbvec!["COL0"]            // symbol
+			sbvec!["COL0"],           // symbol
+			None,                     // royalty
 		));
 
 		// Create HEADWARE collection (1)
@@ -485,7 +975,8 @@ fn nested_equip_works() {
 			Origin::signed(ALICE),
 			stb("headware-collection"), // metadata
 			Some(5),                    // max
-			sbvec!["COL1"]              // symbol
+			sbvec!["COL1"],             // symbol
+			None,                       // royalty
 		));
 
 		// Create GEM collection (2)
@@ -493,17 +984,17 @@ fn nested_equip_works() {
 			Origin::signed(ALICE),
 			stb("gem-collection"), // metadata
 			Some(5),               // max
-			sbvec!["COL2"]         // symbol
+			sbvec!["COL2"],        // symbol
+			None,                  // royalty
 		));
 
 		// Mint PERSON 0
 		assert_ok!(RmrkCore::mint_nft(
 			Origin::signed(ALICE),
-			None,                             // owner
-			0,                                // collection ID
-			Some(ALICE),                      // recipient
-			Some(Permill::from_float(1.525)), // royalties
-			stb("ipfs://person-0-metadata"),  // metadata
+			None,                                       // owner
+			0,                                          // collection ID
+			royalty(ALICE, Permill::from_float(1.525)), // royalty
+			stb("ipfs://person-0-metadata"),            // metadata
 			true,
 			None,
 		));
@@ -511,11 +1002,10 @@ fn nested_equip_works() {
 		// Mint HAT 0
 		assert_ok!(RmrkCore::mint_nft(
 			Origin::signed(ALICE),
-			None,                             // owner
-			1,                                // collection ID
-			Some(ALICE),                      // recipient
-			Some(Permill::from_float(1.525)), // royalties
-			stb("hat-0"),                     // metadata
+			None,                                       // owner
+			1,                                          // collection ID
+			royalty(ALICE, Permill::from_float(1.525)), // royalty
+			stb("hat-0"),                               // metadata
 			true,
 			None,
 		));
@@ -523,11 +1013,10 @@ fn nested_equip_works() {
 		// Mint GEM 0
 		assert_ok!(RmrkCore::mint_nft(
 			Origin::signed(ALICE),
-			None,                             // owner
-			2,                                // collection ID
-			Some(ALICE),                      // recipient
-			Some(Permill::from_float(1.525)), // royalties
-			stb("gem-0"),                     // metadata
+			None,                                       // owner
+			2,                                          // collection ID
+			royalty(ALICE, Permill::from_float(1.525)), // royalty
+			stb("gem-0"),                               // metadata
 			true,
 			None,
 		));
@@ -14,6 +14,7 @@ use frame_support::{
 };
 use frame_system::{ensure_signed, RawOrigin};
 
+use sp_runtime::traits::{Saturating, Zero};
 use sp_std::prelude::*;
 
 pub use pallet::*;
@@ -41,6 +42,7 @@ pub mod pallet {
 	pub type InstanceInfoOf<T> = NftInfo<
 		<T as frame_system::Config>::AccountId,
 		BoundedVec<u8, <T as pallet_uniques::Config>::StringLimit>,
+		pallet_rmrk_core::BoundedRoyaltySharesOf<T>,
 	>;
 
 	pub type BalanceOf<T> =
@@ -126,6 +128,13 @@ pub mod pallet {
 			nft_id: NftId,
 			price: BalanceOf<T>,
 		},
+		/// Royalty of a sold token was paid to its recipient
+		RoyaltyPaid {
+			collection_id: CollectionId,
+			nft_id: NftId,
+			recipient: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 		/// Token listed on Marketplace
 		TokenListed {
 			owner: T::AccountId,
@@ -201,7 +210,11 @@ pub mod pallet {
 		/// 	- `collection_id` - Collection id of the RMRK NFT
 		/// 	- `nft_id` - NFT id of the RMRK NFT
 		/// 	- `amount` - Optional price at which buyer purchased at
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[pallet::weight(
+			10_000 +
+				T::DbWeight::get().reads_writes(1, 1) +
+				pallet_rmrk_core::Pallet::<T>::subtree_weight()
+		)]
 		#[transactional]
 		pub fn buy(
 			origin: OriginFor<T>,
@@ -404,7 +417,11 @@ pub mod pallet {
 		// - `collection_id` - Collection id of the RMRK NFT
 		// - `nft_id` - NFT id of the RMRK NFT
 		// - `offerer` - Account that made the offer
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[pallet::weight(
+			10_000 +
+				T::DbWeight::get().reads_writes(1, 1) +
+				pallet_rmrk_core::Pallet::<T>::subtree_weight()
+		)]
 		#[transactional]
 		pub fn accept_offer(
 			origin: OriginFor<T>,
@@ -505,11 +522,35 @@ where
 		// Set NFT Lock status to false to facilitate the purchase
 		pallet_rmrk_core::Pallet::<T>::set_lock((collection_id, nft_id), false);
 
+		// Pay each royalty share, if any, out of the list price
+		let mut owner_amount = list_price;
+		if let Some(royalty) = pallet_rmrk_core::Pallet::<T>::nft_royalty(collection_id, nft_id) {
+			for (recipient, share) in royalty.shares {
+				let royalty_amount = share * list_price;
+				if royalty_amount.is_zero() {
+					continue
+				}
+				<T as pallet::Config>::Currency::transfer(
+					&buyer,
+					&recipient,
+					royalty_amount,
+					ExistenceRequirement::KeepAlive,
+				)?;
+				owner_amount = owner_amount.saturating_sub(royalty_amount);
+				Self::deposit_event(Event::RoyaltyPaid {
+					collection_id,
+					nft_id,
+					recipient,
+					amount: royalty_amount,
+				});
+			}
+		}
+
 		// Transfer currency then transfer the NFT
 		<T as pallet::Config>::Currency::transfer(
 			&buyer,
 			&owner,
-			list_price,
+			owner_amount,
 			ExistenceRequirement::KeepAlive,
 		)?;
 
@@ -7,6 +7,8 @@ use crate::mock::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{Event as MockEvent, *};
 
+use pallet_rmrk_core::RoyaltyInfoOf;
+use rmrk_traits::RoyaltyInfo;
 use sp_runtime::Permill;
 use sp_std::{convert::TryInto, vec::Vec};
 
@@ -31,9 +33,23 @@ macro_rules! bvec {
 	}
 }
 
+/// Shortcut for a royalty paid entirely to a single recipient
+fn royalty(
+	recipient: <Test as frame_system::Config>::AccountId,
+	amount: Permill,
+) -> Option<RoyaltyInfoOf<Test>> {
+	Some(RoyaltyInfo { shares: bvec![(recipient, amount)] })
+}
+
 /// Shortcut for a test collection creation (Alice is issue, max NFTs is 5)
 fn basic_collection() -> DispatchResult {
-	RmrkCore::create_collection(Origin::signed(ALICE), bvec![0u8; 20], Some(5), bvec![0u8; 15])
+	RmrkCore::create_collection(
+		Origin::signed(ALICE),
+		bvec![0u8; 20],
+		Some(5),
+		bvec![0u8; 15],
+		None,
+	)
 }
 
 /// Shortcut for a basic mint (Alice owner, Collection ID 0, Royalty 1.525)
@@ -42,8 +58,7 @@ fn basic_mint() -> DispatchResult {
 		Origin::signed(ALICE),
 		Some(ALICE),
 		COLLECTION_ID_0,
-		Some(ALICE),
-		Some(Permill::from_float(1.525)),
+		royalty(ALICE, Permill::from_float(1.525)),
 		bvec![0u8; 20],
 		true,
 		None,
@@ -130,8 +145,7 @@ fn list_non_transferable_fail() {
 			Origin::signed(ALICE),
 			Some(ALICE),
 			COLLECTION_ID_0,
-			Some(ALICE),
-			Some(Permill::from_float(1.525)),
+			royalty(ALICE, Permill::from_float(1.525)),
 			bvec![0u8; 20],
 			false, // non-transferable
 			None,
@@ -194,6 +208,50 @@ fn buy_works() {
 	});
 }
 
+#[test]
+fn buy_pays_collection_default_royalty() {
+	new_test_ext().execute_with(|| {
+		// Create a collection with a 10% default royalty for CHARLIE
+		assert_ok!(RmrkCore::create_collection(
+			Origin::signed(ALICE),
+			bvec![0u8; 20],
+			Some(5),
+			bvec![0u8; 15],
+			royalty(CHARLIE, Permill::from_percent(10)),
+		));
+		// Mint an NFT without its own royalty
+		assert_ok!(RmrkCore::mint_nft(
+			Origin::signed(ALICE),
+			Some(ALICE),
+			COLLECTION_ID_0,
+			None,
+			bvec![0u8; 20],
+			true,
+			None,
+		));
+		// ALICE lists the NFT successfully
+		assert_ok!(RmrkMarket::list(
+			Origin::signed(ALICE),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			1_000u128,
+			None,
+		));
+		let alice_balance = Balances::free_balance(ALICE);
+		let charlie_balance = Balances::free_balance(CHARLIE);
+		// BOB buys the NFT
+		assert_ok!(RmrkMarket::buy(
+			Origin::signed(BOB),
+			COLLECTION_ID_0,
+			NFT_ID_0,
+			Some(1_000u128),
+		));
+		// CHARLIE receives the royalty and ALICE the rest of the price
+		assert_eq!(Balances::free_balance(CHARLIE), charlie_balance + 100);
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 900);
+	});
+}
+
 #[test]
 fn buy_wont_work_after_list_expires() {
 	new_test_ext().execute_with(|| {
@@ -0,0 +1,37 @@
+// Copyright (C) 2021-2022 RMRK
+// This file is part of rmrk-substrate.
+// License: Apache 2.0 modified by RMRK, see LICENSE.md
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use rmrk_traits::{primitives::*, EffectiveResource, PendingItem, PropertyType};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Queries over the state of the RMRK pallets
+	pub trait RmrkApi<AccountId, BoundedString, BoundedParts>
+	where
+		AccountId: Codec,
+		BoundedString: Codec,
+		BoundedParts: Codec,
+	{
+		/// Get the property schema of a collection as (key, type) pairs
+		fn property_schema(collection_id: CollectionId) -> Vec<(Vec<u8>, PropertyType)>;
+
+		/// Get the resources an NFT displays: its own accepted resources followed by the shared
+		/// resources of its collection it didn't opt out of
+		fn nft_resources(
+			collection_id: CollectionId,
+			nft_id: NftId,
+		) -> Vec<EffectiveResource<BoundedString, BoundedParts>>;
+
+		/// Get a page of the NFTs root-owned by an account, either directly or nested in NFTs
+		/// it owns, skipping the first `start` NFTs and returning at most `count`
+		fn nfts_owned_by(account: AccountId, start: u32, count: u32) -> Vec<(CollectionId, NftId)>;
+
+		/// Get a page of the pending NFTs and resource changes awaiting the decision of an
+		/// account, skipping the first `start` items and returning at most `count`
+		fn pending_inbox(account: AccountId, start: u32, count: u32) -> Vec<PendingItem>;
+	}
+}
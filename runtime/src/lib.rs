@@ -20,6 +20,7 @@ use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
 // A few exports that help ease life for downstream crates.
+use frame_support::BoundedVec;
 pub use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{AsEnsureOriginWithArg, Contains, KeyOwnerProofSystem, Randomness, StorageInfo},
@@ -36,6 +37,10 @@ pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::CurrencyAdapter;
+use rmrk_traits::{
+	primitives::{CollectionId, NftId, PartId},
+	EffectiveResource, PendingItem, PropertyType,
+};
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
 pub use sp_runtime::{Perbill, Permill};
@@ -121,12 +126,13 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// The version of the runtime specification. A full node will not attempt to use its native
 	//   runtime in substitute for the on-chain Wasm runtime unless all of `spec_name`,
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
-	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
-	//   the compatible custom types.
-	spec_version: 100,
+	// This value started at 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
+	//   the compatible custom types, and is bumped with every upgrade migrating the RMRK pallets.
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	// Bumped with changes to the calls of the RMRK pallets, such as royalty shares on mint.
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -338,10 +344,25 @@ parameter_types! {
 	pub const PartsLimit: u32 = 25;
 	pub const MaxPriorities: u32 = 25;
 	pub const CollectionSymbolLimit: u32 = 100;
-	pub const MaxResourcesOnMint: u32 = 100;
+	pub const MaxResourcesOnMint: u32 = 25;
+	pub const MaxBatchMint: u32 = 100;
+	pub const MaxBatchAccept: u32 = 100;
+	pub const MaxChildrenPerNft: u32 = 100;
+	pub const MaxDescendants: u32 = 20;
+	pub const MaxResourcesPerNft: u32 = 25;
+	pub const MaxPropertiesPerNft: u32 = 25;
+	pub const MaxPolicyEntries: u32 = 50;
+	pub const PendingTimeout: BlockNumber = 7 * DAYS;
+	pub const MaxBulkResources: u32 = 100;
+	pub const MaxRoyaltyRecipients: u32 = 10;
+	pub const MaxPropertiesBatch: u32 = 25;
+	pub const ResourceDeposit: Balance = 10 * CENTS;
+	pub const PropertyDepositPerByte: Balance = CENTS;
+	pub const ChildDeposit: Balance = 10 * CENTS;
 }
 
 impl pallet_rmrk_core::Config for Runtime {
+	type Currency = Balances;
 	type Event = Event;
 	type ProtocolOrigin = frame_system::EnsureRoot<AccountId>;
 	type MaxRecursions = MaxRecursions;
@@ -350,6 +371,23 @@ impl pallet_rmrk_core::Config for Runtime {
 	type MaxPriorities = MaxPriorities;
 	type CollectionSymbolLimit = CollectionSymbolLimit;
 	type MaxResourcesOnMint = MaxResourcesOnMint;
+	type MaxBatchMint = MaxBatchMint;
+	type MaxBatchAccept = MaxBatchAccept;
+	type MaxChildrenPerNft = MaxChildrenPerNft;
+	type MaxDescendants = MaxDescendants;
+	type MaxResourcesPerNft = MaxResourcesPerNft;
+	type MaxPropertiesPerNft = MaxPropertiesPerNft;
+	type MaxPolicyEntries = MaxPolicyEntries;
+	type PendingTimeout = PendingTimeout;
+	type MaxBulkResources = MaxBulkResources;
+	type MaxRoyaltyRecipients = MaxRoyaltyRecipients;
+	type MaxPropertiesBatch = MaxPropertiesBatch;
+	type OnResourceRemoved = RmrkEquip;
+	type OnNftBurned = RmrkEquip;
+	type Bases = RmrkEquip;
+	type ResourceDeposit = ResourceDeposit;
+	type PropertyDepositPerByte = PropertyDepositPerByte;
+	type ChildDeposit = ChildDeposit;
 }
 
 parameter_types! {
@@ -453,6 +491,10 @@ pub type SignedExtra = (
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
+/// Bounded string of the RMRK pallets, as returned by the RMRK runtime API.
+pub type RmrkString = BoundedVec<u8, UniquesStringLimit>;
+/// Bounded list of the base parts of a resource, as returned by the RMRK runtime API.
+pub type RmrkParts = BoundedVec<PartId, PartsLimit>;
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -593,6 +635,27 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl rmrk_rpc_runtime_api::RmrkApi<Block, AccountId, RmrkString, RmrkParts> for Runtime {
+		fn property_schema(collection_id: CollectionId) -> Vec<(Vec<u8>, PropertyType)> {
+			RmrkCore::collection_property_schema(collection_id)
+		}
+
+		fn nft_resources(
+			collection_id: CollectionId,
+			nft_id: NftId,
+		) -> Vec<EffectiveResource<RmrkString, RmrkParts>> {
+			RmrkCore::nft_effective_resources(collection_id, nft_id)
+		}
+
+		fn nfts_owned_by(account: AccountId, start: u32, count: u32) -> Vec<(CollectionId, NftId)> {
+			RmrkCore::nfts_owned_by(&account, start, count)
+		}
+
+		fn pending_inbox(account: AccountId, start: u32, count: u32) -> Vec<PendingItem> {
+			RmrkCore::pending_inbox_of(&account, start, count)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -6,7 +6,7 @@ use super::{
 	part::{EquippableList, PartType},
 	theme::Theme,
 };
-use crate::primitives::{BaseId, ResourceId, SlotId};
+use crate::primitives::{BaseId, PartId, ResourceId, SlotId};
 use codec::{Decode, Encode};
 use frame_support::pallet_prelude::MaxEncodedLen;
 use scale_info::TypeInfo;
@@ -58,3 +58,26 @@ pub trait Base<AccountId, CollectionId, NftId, BoundedString, BoundedParts, Boun
 		theme: Theme<BoundedString, BoundedThemeProperties>,
 	) -> Result<(), DispatchError>;
 }
+
+/// Read access to bases, used to validate resources referencing them.
+pub trait InspectBase {
+	/// Whether the base exists
+	fn base_exists(base_id: BaseId) -> bool;
+	/// Whether the part exists and belongs to the base
+	fn part_exists(base_id: BaseId, part_id: PartId) -> bool;
+	/// Whether the part exists, belongs to the base and is a slot part
+	fn is_slot_part(base_id: BaseId, part_id: PartId) -> bool;
+}
+
+/// Accepts any base, for runtimes without a base pallet.
+impl InspectBase for () {
+	fn base_exists(_base_id: BaseId) -> bool {
+		true
+	}
+	fn part_exists(_base_id: BaseId, _part_id: PartId) -> bool {
+		true
+	}
+	fn is_slot_part(_base_id: BaseId, _part_id: PartId) -> bool {
+		true
+	}
+}
@@ -7,30 +7,33 @@ use frame_support::pallet_prelude::MaxEncodedLen;
 use scale_info::TypeInfo;
 use sp_runtime::{DispatchError, DispatchResult, RuntimeDebug};
 
-use crate::primitives::*;
+use crate::{nft::RoyaltyInfo, primitives::*};
 use sp_std::result::Result;
 
 /// Collection info.
 #[cfg_attr(feature = "std", derive(PartialEq, Eq))]
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct CollectionInfo<BoundedString, BoundedSymbol, AccountId> {
+pub struct CollectionInfo<BoundedString, BoundedSymbol, AccountId, BoundedRoyaltyShares> {
 	/// Current bidder and bid price.
 	pub issuer: AccountId,
 	pub metadata: BoundedString,
 	pub max: Option<u32>,
 	pub symbol: BoundedSymbol,
 	pub nfts_count: u32,
+	/// Default royalty of NFTs minted without their own royalty
+	pub royalty: Option<RoyaltyInfo<BoundedRoyaltyShares>>,
 }
 
 /// Abstraction over a Collection system.
 #[allow(clippy::upper_case_acronyms)]
-pub trait Collection<BoundedString, BoundedSymbol, AccountId> {
+pub trait Collection<BoundedString, BoundedSymbol, AccountId, BoundedRoyaltyShares> {
 	fn issuer(collection_id: CollectionId) -> Option<AccountId>;
 	fn collection_create(
 		issuer: AccountId,
 		metadata: BoundedString,
 		max: Option<u32>,
 		symbol: BoundedSymbol,
+		royalty: Option<RoyaltyInfo<BoundedRoyaltyShares>>,
 	) -> Result<CollectionId, DispatchError>;
 	fn collection_burn(issuer: AccountId, collection_id: CollectionId) -> DispatchResult;
 	fn collection_change_issuer(
@@ -41,4 +44,15 @@ pub trait Collection<BoundedString, BoundedSymbol, AccountId> {
 		sender: AccountId,
 		collection_id: CollectionId,
 	) -> Result<CollectionId, DispatchError>;
+	fn collection_set_royalty(
+		sender: AccountId,
+		collection_id: CollectionId,
+		royalty: Option<RoyaltyInfo<BoundedRoyaltyShares>>,
+	) -> DispatchResult;
+	fn collection_set_metadata(
+		sender: AccountId,
+		collection_id: CollectionId,
+		metadata: BoundedString,
+		freeze: bool,
+	) -> DispatchResult;
 }
@@ -8,21 +8,24 @@ pub mod base;
 pub mod collection;
 pub mod nft;
 pub mod part;
+pub mod pending;
 pub mod priority;
 pub mod property;
 pub mod resource;
 pub mod theme;
 
-pub use base::{Base, BaseInfo};
+pub use base::{Base, BaseInfo, InspectBase};
 pub use part::{EquippableList, FixedPart, PartType, SlotPart};
+pub use pending::PendingItem;
 pub use theme::{Theme, ThemeProperty};
 // pub use part::{PartInfo};
 pub use collection::{Collection, CollectionInfo};
-pub use nft::{AccountIdOrCollectionNftTuple, Nft, NftInfo, RoyaltyInfo};
+pub use nft::{AccountIdOrCollectionNftTuple, Nft, NftInfo, OnNftBurned, RoyaltyInfo};
 pub use priority::Priority;
-pub use property::Property;
+pub use property::{Property, PropertyPermission, PropertyType};
 pub use resource::{
-	BasicResource, ComposableResource, Resource, ResourceInfo, ResourceTypes, SlotResource,
+	BasicResource, ComposableResource, EffectiveResource, OnResourceRemoved, Resource,
+	ResourceInfo, ResourceTypes, SlotResource,
 };
 pub mod primitives {
 	pub type CollectionId = u32;
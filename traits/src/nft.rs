@@ -8,7 +8,6 @@ use sp_runtime::{DispatchError, RuntimeDebug};
 use sp_std::cmp::Eq;
 
 use frame_support::pallet_prelude::*;
-use sp_runtime::Permill;
 
 use crate::primitives::*;
 use sp_std::result::Result;
@@ -23,24 +22,22 @@ pub enum AccountIdOrCollectionNftTuple<AccountId> {
 	CollectionAndNftTuple(CollectionId, NftId),
 }
 
-/// Royalty information (recipient and amount)
-#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
-#[derive(Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct RoyaltyInfo<AccountId> {
-	/// Recipient (AccountId) of the royalty
-	pub recipient: AccountId,
-	/// Amount (Permill) of the royalty
-	pub amount: Permill,
+/// Royalty information (recipients and their shares)
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RoyaltyInfo<BoundedRoyaltyShares> {
+	/// Recipients (AccountId) of the royalty and their share (Permill) of each sale, the shares
+	/// may not exceed 100% in total
+	pub shares: BoundedRoyaltyShares,
 }
 
 /// Nft info.
 #[cfg_attr(feature = "std", derive(PartialEq, Eq))]
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct NftInfo<AccountId, BoundedString> {
+pub struct NftInfo<AccountId, BoundedString, BoundedRoyaltyShares> {
 	/// The owner of the NFT, can be either an Account or a tuple (CollectionId, NftId)
 	pub owner: AccountIdOrCollectionNftTuple<AccountId>,
 	/// Royalty (optional)
-	pub royalty: Option<RoyaltyInfo<AccountId>>,
+	pub royalty: Option<RoyaltyInfo<BoundedRoyaltyShares>>,
 	/// Arbitrary data about an instance, e.g. IPFS hash
 	pub metadata: BoundedString,
 	/// Equipped state
@@ -52,15 +49,14 @@ pub struct NftInfo<AccountId, BoundedString> {
 }
 /// Abstraction over a Nft system.
 #[allow(clippy::upper_case_acronyms)]
-pub trait Nft<AccountId, BoundedString> {
+pub trait Nft<AccountId, BoundedString, BoundedRoyaltyShares> {
 	type MaxRecursions: Get<u32>;
 
 	fn nft_mint(
 		sender: AccountId,
 		owner: AccountId,
 		collection_id: CollectionId,
-		royalty_recipient: Option<AccountId>,
-		royalty_amount: Option<Permill>,
+		royalty: Option<RoyaltyInfo<BoundedRoyaltyShares>>,
 		metadata: BoundedString,
 		transferable: bool,
 	) -> Result<(CollectionId, NftId), DispatchError>;
@@ -68,8 +64,7 @@ pub trait Nft<AccountId, BoundedString> {
 		sender: AccountId,
 		owner: (CollectionId, NftId),
 		collection_id: CollectionId,
-		royalty_recipient: Option<AccountId>,
-		royalty_amount: Option<Permill>,
+		royalty: Option<RoyaltyInfo<BoundedRoyaltyShares>>,
 		metadata: BoundedString,
 		transferable: bool,
 	) -> Result<(CollectionId, NftId), DispatchError>;
@@ -94,6 +89,29 @@ pub trait Nft<AccountId, BoundedString> {
 		sender: AccountId,
 		collection_id: CollectionId,
 		nft_id: NftId,
+	) -> Result<(AccountId, AccountIdOrCollectionNftTuple<AccountId>), DispatchError>;
+	fn nft_reject_and_burn(
+		sender: AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
 		max_recursions: u32,
 	) -> Result<(AccountId, CollectionId, NftId), DispatchError>;
+	fn nft_set_metadata(
+		sender: AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		metadata: BoundedString,
+		freeze: bool,
+	) -> DispatchResult;
+}
+
+/// Handler for the burning of an NFT, used to clean up state keyed by or referencing it.
+pub trait OnNftBurned {
+	/// Called before NFT `(collection_id, nft_id)` is removed from storage, so it can still be
+	/// read.
+	fn on_nft_burned(collection_id: CollectionId, nft_id: NftId);
+}
+
+impl OnNftBurned for () {
+	fn on_nft_burned(_collection_id: CollectionId, _nft_id: NftId) {}
 }
@@ -0,0 +1,27 @@
+// Copyright (C) 2021-2022 RMRK
+// This file is part of rmrk-substrate.
+// License: Apache 2.0 modified by RMRK, see LICENSE.md
+
+use codec::{Decode, Encode};
+use frame_support::pallet_prelude::MaxEncodedLen;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+use crate::primitives::*;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Something awaiting the decision of the root owner of an NFT.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum PendingItem {
+	/// An NFT sent or minted to a recipient the sender doesn't own, to accept or reject
+	Nft { collection_id: CollectionId, nft_id: NftId },
+	/// A resource added by the collection issuer, to accept or reject
+	ResourceAddition { collection_id: CollectionId, nft_id: NftId, resource_id: ResourceId },
+	/// A resource removal requested by the collection issuer, to accept
+	ResourceRemoval { collection_id: CollectionId, nft_id: NftId, resource_id: ResourceId },
+	/// A resource replacement proposed by the collection issuer, to accept or reject
+	ResourceReplacement { collection_id: CollectionId, nft_id: NftId, resource_id: ResourceId },
+}
@@ -2,10 +2,52 @@
 // This file is part of rmrk-substrate.
 // License: Apache 2.0 modified by RMRK, see LICENSE.md
 
-use sp_runtime::DispatchResult;
+use codec::{Decode, Encode};
+use frame_support::pallet_prelude::MaxEncodedLen;
+use scale_info::TypeInfo;
+use sp_runtime::{DispatchResult, RuntimeDebug};
 
 use crate::primitives::*;
 
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Who may write a property key of a collection's NFTs.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum PropertyPermission {
+	/// Only the collection issuer, while it root-owns the NFT
+	IssuerOnly,
+	/// Only the root owner of the NFT
+	OwnerOnly,
+	/// Either the collection issuer or the root owner of the NFT
+	IssuerAndOwner,
+	/// Only the collection issuer, and only once: the property can never be changed or removed
+	/// after being set
+	Immutable,
+}
+
+impl Default for PropertyPermission {
+	fn default() -> Self {
+		PropertyPermission::IssuerOnly
+	}
+}
+
+/// Type of the values of a property key, declared in a collection's property schema.
+///
+/// Values are stored SCALE encoded, except `String` which is stored as raw UTF-8 bytes.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum PropertyType {
+	U32,
+	I64,
+	Bool,
+	String,
+	AccountId,
+	/// A `(CollectionId, NftId)` tuple
+	NftReference,
+}
+
 /// Abstraction over a Property system.
 #[allow(clippy::upper_case_acronyms)]
 pub trait Property<KeyLimit, ValueLimit, AccountId> {
@@ -16,4 +58,35 @@ pub trait Property<KeyLimit, ValueLimit, AccountId> {
 		key: KeyLimit,
 		value: ValueLimit,
 	) -> DispatchResult;
+	fn property_remove(
+		sender: AccountId,
+		collection_id: CollectionId,
+		maybe_nft_id: Option<NftId>,
+		key: KeyLimit,
+	) -> DispatchResult;
+	fn property_set_permission(
+		sender: AccountId,
+		collection_id: CollectionId,
+		key: KeyLimit,
+		permission: PropertyPermission,
+	) -> DispatchResult;
+	fn property_set_schema(
+		sender: AccountId,
+		collection_id: CollectionId,
+		key: KeyLimit,
+		property_type: Option<PropertyType>,
+	) -> DispatchResult;
+	fn owner_property_set(
+		sender: AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		key: KeyLimit,
+		value: ValueLimit,
+	) -> DispatchResult;
+	fn owner_property_remove(
+		sender: AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		key: KeyLimit,
+	) -> DispatchResult;
 }
@@ -134,6 +134,16 @@ pub struct ResourceInfo<BoundedString, BoundedParts> {
 	pub pending_removal: bool,
 }
 
+/// A resource an NFT displays, either held by the NFT itself or shared by its collection.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum EffectiveResource<BoundedString, BoundedParts> {
+	/// A resource added to the NFT itself
+	Nft(ResourceInfo<BoundedString, BoundedParts>),
+	/// A resource shared by every NFT of the collection that didn't opt out of it
+	Collection { id: ResourceId, resource: ResourceTypes<BoundedString, BoundedParts> },
+}
+
 /// Abstraction over a Resource system.
 pub trait Resource<BoundedString, AccountId, BoundedPart> {
 	fn resource_add(
@@ -161,4 +171,66 @@ pub trait Resource<BoundedString, AccountId, BoundedPart> {
 		nft_id: NftId,
 		resource_id: ResourceId,
 	) -> DispatchResult;
+	fn reject(
+		sender: AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		resource_id: ResourceId,
+	) -> DispatchResult;
+	fn resource_replace(
+		sender: AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		resource_id: ResourceId,
+		resource: ResourceTypes<BoundedString, BoundedPart>,
+	) -> DispatchResult;
+	fn accept_replacement(
+		sender: AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		resource_id: ResourceId,
+	) -> DispatchResult;
+	fn collection_resource_add(
+		sender: AccountId,
+		collection_id: CollectionId,
+		resource: ResourceTypes<BoundedString, BoundedPart>,
+	) -> Result<ResourceId, DispatchError>;
+	fn collection_resource_remove(
+		sender: AccountId,
+		collection_id: CollectionId,
+		resource_id: ResourceId,
+	) -> DispatchResult;
+	fn collection_resource_set_opt_out(
+		sender: AccountId,
+		collection_id: CollectionId,
+		nft_id: NftId,
+		resource_id: ResourceId,
+		opt_out: bool,
+	) -> DispatchResult;
+}
+
+/// Handler for the removal of a resource from an NFT, used to clean up state relying on it.
+pub trait OnResourceRemoved {
+	/// Called after resource `resource_id` was removed from NFT `(collection_id, nft_id)`.
+	///
+	/// - `slots`: The (base, slot) pairs the resource made the NFT equippable into
+	/// - `released_base`: Base the NFT no longer has any composable resource for, if any
+	fn on_resource_removed(
+		collection_id: CollectionId,
+		nft_id: NftId,
+		resource_id: ResourceId,
+		slots: &[(BaseId, SlotId)],
+		released_base: Option<BaseId>,
+	);
+}
+
+impl OnResourceRemoved for () {
+	fn on_resource_removed(
+		_collection_id: CollectionId,
+		_nft_id: NftId,
+		_resource_id: ResourceId,
+		_slots: &[(BaseId, SlotId)],
+		_released_base: Option<BaseId>,
+	) {
+	}
 }